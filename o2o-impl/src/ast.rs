@@ -9,7 +9,7 @@ use syn2 as syn;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Attribute, DataEnum, DataStruct, DeriveInput, Fields, Generics, Ident, Index, Member, Path, Result};
+use syn::{Attribute, DataEnum, DataStruct, DeriveInput, Fields, Generics, Ident, Index, Member, Path, Result, Visibility};
 
 #[derive(Default)]
 struct Context {
@@ -19,6 +19,7 @@ struct Context {
 
 pub(crate) struct Struct<'a> {
     pub attrs: DataTypeAttrs,
+    pub vis: &'a Visibility,
     pub ident: &'a Ident,
     pub generics: &'a Generics,
     pub fields: Vec<Field>,
@@ -32,6 +33,7 @@ impl<'a> Struct<'a> {
         let fields = Field::multiple_from_syn(&mut Default::default(), &data.fields, bark)?;
         Ok(Struct {
             attrs,
+            vis: &node.vis,
             ident: &node.ident,
             generics: &node.generics,
             fields,
@@ -47,7 +49,8 @@ pub(crate) struct Field {
     pub idx: usize,
     pub member: Member,
     pub member_str: String,
-    pub ty: Option<Path>
+    pub ty: Option<Path>,
+    pub is_array: bool,
 }
 
 impl<'a> Field {
@@ -94,7 +97,8 @@ impl<'a> Field {
             ty: match &node.ty {
                 syn::Type::Path(p) => Some(p.path.clone()),
                 _ => None
-            }
+            },
+            is_array: matches!(node.ty, syn::Type::Array(_)),
         })
     }
 }
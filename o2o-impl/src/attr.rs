@@ -8,11 +8,11 @@ use syn2 as syn;
 
 use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::parse::{Parse, ParseBuffer, ParseStream};
+use syn::parse::{Parse, ParseBuffer, ParseStream, Parser};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::{Brace, Bracket, Comma, Paren};
-use syn::{braced, bracketed, parenthesized, AngleBracketedGenericArguments, Attribute, Error, Ident, Member, PathArguments, Result, Token, WherePredicate};
+use syn::{braced, bracketed, parenthesized, AngleBracketedGenericArguments, Attribute, Error, Ident, LitBool, Member, PathArguments, Result, Token, Visibility, WherePredicate};
 
 use crate::ast::SynDataTypeMember;
 use crate::kw;
@@ -76,7 +76,11 @@ pub(crate) enum DataTypeInstruction {
     Ghosts(GhostsAttr),
     Where(WhereAttr),
     ChildParents(ChildParentsAttr),
+    Remote(RemoteAttr),
+    FromStr(StrAttr),
+    Display(StrAttr),
     AllowUnknown,
+    Transparent,
 
     Misplaced { instr: &'static str, span: Span, own: bool },
     Misnamed { instr: &'static str, span: Span, guess_name: &'static str, own: bool },
@@ -95,6 +99,8 @@ pub(crate) enum MemberInstruction {
     Lit(LitAttr),
     Pat(PatAttr),
     VariantTypeHint(VariantTypeHintAttr),
+    MapOpt(MapOptAttr),
+    MapItems(MapItemsAttr),
     Repeat(MemberRepeatAttr),
     SkipRepeat,
     StopRepeat,
@@ -224,6 +230,10 @@ pub(crate) struct DataTypeAttrs {
     pub ghosts_attrs: Vec<GhostsAttr>,
     pub where_attrs: Vec<WhereAttr>,
     pub child_parents_attrs: Vec<ChildParentsAttr>,
+    pub remote_attrs: Vec<RemoteAttr>,
+    pub from_str_attrs: Vec<StrAttr>,
+    pub display_attrs: Vec<StrAttr>,
+    pub transparent: bool,
 
     pub error_instrs: Vec<DataTypeInstruction>,
 }
@@ -254,6 +264,10 @@ impl<'a> DataTypeAttrs {
             .find(|x| x.container_ty.is_some() && x.container_ty.as_ref().unwrap() == container_ty)
             .or_else(|| self.child_parents_attrs.iter().find(|x| x.container_ty.is_none()))
     }
+
+    pub(crate) fn remote_attr(&'a self) -> Option<&'a RemoteAttr> {
+        self.remote_attrs.first()
+    }
 }
 
 type MemberRepeatFor = [bool; 5];
@@ -334,6 +348,8 @@ pub(crate) struct MemberAttrs {
     pub skip_repeat: bool,
     pub stop_repeat: bool,
     pub type_hint_attrs: Vec<VariantTypeHintAttr>,
+    pub map_opt_attrs: Vec<MapOptAttr>,
+    pub map_items_attrs: Vec<MapItemsAttr>,
 
     pub error_instrs: Vec<MemberInstruction>,
 }
@@ -383,6 +399,10 @@ impl<'a> MemberAttrs {
             .or_else(|| self.lit_attrs.iter().find(|x| x.container_ty.is_none()))
     }
 
+    pub(crate) fn str_lit(&'a self) -> Option<&'a LitAttr>{
+        self.lit_attrs.iter().find(|x| x.container_ty.is_none())
+    }
+
     pub(crate) fn pat(&'a self, container_ty: &TypePath) -> Option<&PatAttr>{
         self.pat_attrs.iter()
             .find(|x| x.container_ty.is_some() && x.container_ty.as_ref().unwrap() == container_ty)
@@ -395,6 +415,18 @@ impl<'a> MemberAttrs {
             .or_else(|| self.type_hint_attrs.iter().find(|x| x.container_ty.is_none()))
     }
 
+    pub(crate) fn map_opt(&'a self, container_ty: &TypePath) -> Option<&'a MapOptAttr>{
+        self.map_opt_attrs.iter()
+            .find(|x| x.container_ty.is_some() && x.container_ty.as_ref().unwrap() == container_ty)
+            .or_else(|| self.map_opt_attrs.iter().find(|x| x.container_ty.is_none()))
+    }
+
+    pub(crate) fn map_items(&'a self, container_ty: &TypePath) -> Option<&'a MapItemsAttr>{
+        self.map_items_attrs.iter()
+            .find(|x| x.container_ty.is_some() && x.container_ty.as_ref().unwrap() == container_ty)
+            .or_else(|| self.map_items_attrs.iter().find(|x| x.container_ty.is_none()))
+    }
+
     pub(crate) fn has_parent_attr(&'a self, container_ty: &TypePath) -> bool {
         self.parent_attrs.iter().any(|x| x.container_ty.is_none() || x.container_ty.as_ref().unwrap() == container_ty)
     }
@@ -429,6 +461,8 @@ impl<'a> MemberAttrs {
         if let Some(repeat) = other.repeat {
             if repeat.repeat_for[&MemberAttrType::Attr] {
                 self.attrs.extend(other.attrs);
+                self.map_opt_attrs.extend(other.map_opt_attrs);
+                self.map_items_attrs.extend(other.map_items_attrs);
             }
             if repeat.repeat_for[&MemberAttrType::Child] {
                 self.child_attrs.extend(other.child_attrs);
@@ -532,9 +566,35 @@ pub(crate) struct TraitAttrCore {
     pub attribute: Option<TokenStream>,
     pub impl_attribute: Option<TokenStream>,
     pub inner_attribute: Option<TokenStream>,
+    pub check_shape: bool,
+    pub rename_all: Option<RenameRule>,
+    pub standalone_fn: Option<StandaloneFn>,
+    pub collect_errors: Option<CollectErrors>,
+    pub error_paths: bool,
+    pub map_err: Option<TokenStream>,
+    pub with: Option<WithArg>,
+    pub is_async: bool,
+    pub diff: bool,
+    pub patch: bool,
+    pub impl_from: bool,
+    pub tag: Option<Member>,
+    pub convert_fields: bool,
+    /// Set by 'infer_bounds', with generic arguments of the instruction type, that should be added to the generic parameters of the implementation.
+    pub infer_bounds: Option<Vec<Ident>>,
+    pub default_init: bool,
 }
 
 impl TraitAttrCore {
+    /// Name of the method that starts a builder for ghost fields without default value, e.g. 'into_entity_builder'.
+    pub(crate) fn builder_fn_ident(&self, fallible: bool) -> Ident {
+        if let Some(standalone_fn) = &self.standalone_fn {
+            return standalone_fn.ident.clone();
+        }
+        let dst_name: String = self.ty.path_str.rsplit("::").next().unwrap().trim_start().chars().take_while(|x| x.is_alphanumeric() || *x == '_').collect();
+        let prefix = if fallible { "try_into" } else { "into" };
+        Ident::new(&format!("{}_{}_builder", prefix, RenameRule::SnakeCase.apply(&dst_name)), Span::call_site())
+    }
+
     fn merge(&mut self, other: Self) -> Result<()> {
         if self.skip_repeat {
             return Ok(());
@@ -595,7 +655,7 @@ impl Parse for TraitAttrCore {
             } else { input.parse::<syn::Path>()?.into() })
         } else { None };
 
-        let mut attr = TraitAttrCore { ty, err_ty, type_hint, init_data: None, update: None, quick_return: None, default_case: None, match_expr: None, repeat: None, skip_repeat: false, stop_repeat: false, attribute: None, impl_attribute: None, inner_attribute: None };
+        let mut attr = TraitAttrCore { ty, err_ty, type_hint, init_data: None, update: None, quick_return: None, default_case: None, match_expr: None, repeat: None, skip_repeat: false, stop_repeat: false, attribute: None, impl_attribute: None, inner_attribute: None, check_shape: false, rename_all: None, standalone_fn: None, collect_errors: None, error_paths: false, map_err: None, with: None, is_async: false, diff: false, patch: false, impl_from: false, tag: None, convert_fields: false, infer_bounds: None, default_init: false };
 
         if !input.peek(Token![|]) {
             return Ok(attr);
@@ -662,11 +722,156 @@ fn parse_trait_instruction_param(input: &syn::parse::ParseBuffer, attr: &mut Tra
         return parse_parenthesized_trait_instruction_param_inner::<kw::impl_attribute, TokenStream>(input, |c| c.parse(), attr.impl_attribute.is_some(), |x| attr.impl_attribute = Some(quote!(#[ #x ])), |a| a.span, "impl_attribute")
     } else if input.peek(kw::inner_attribute) {
         return parse_parenthesized_trait_instruction_param_inner::<kw::inner_attribute, TokenStream>(input, |c| c.parse(), attr.inner_attribute.is_some(), |x| attr.inner_attribute = Some(quote!(#![ #x ])), |a| a.span, "inner_attribute")
+    } else if input.peek(kw::check_shape) {
+        return parse_trait_instruction_param_inner::<kw::check_shape, ()>(input, |_, _| Ok(()), attr.check_shape, |_| attr.check_shape = true, |a| a.span, "check_shape")
+    } else if input.peek(kw::with) {
+        return parse_parenthesized_trait_instruction_param_inner::<kw::with, WithArg>(input, |c| c.parse(), attr.with.is_some(), |x| attr.with = Some(x), |a| a.span, "with")
+    } else if input.peek(kw::map_err) {
+        return parse_parenthesized_trait_instruction_param_inner::<kw::map_err, TokenStream>(input, |c| c.parse(), attr.map_err.is_some(), |x| attr.map_err = Some(x), |a| a.span, "map_err")
+    } else if input.peek(kw::default_init) {
+        return parse_trait_instruction_param_inner::<kw::default_init, ()>(input, |_, _| Ok(()), attr.default_init, |_| attr.default_init = true, |a| a.span, "default_init")
+    } else if input.peek(kw::infer_bounds) {
+        return parse_trait_instruction_param_inner::<kw::infer_bounds, Vec<Ident>>(input, |x, _| {
+            if !x.peek(syn::token::Paren) {
+                return Ok(vec![]);
+            }
+            let content;
+            parenthesized!(content in x);
+            Ok(Punctuated::<Ident, Comma>::parse_separated_nonempty(&content)?.into_iter().collect())
+        }, attr.infer_bounds.is_some(), |x| attr.infer_bounds = Some(x), |a| a.span, "infer_bounds")
+    } else if input.peek(kw::convert_fields) {
+        return parse_trait_instruction_param_inner::<kw::convert_fields, ()>(input, |_, _| Ok(()), attr.convert_fields, |_| attr.convert_fields = true, |a| a.span, "convert_fields")
+    } else if input.peek(kw::tag) {
+        return parse_parenthesized_trait_instruction_param_inner::<kw::tag, Member>(input, |c| c.parse(), attr.tag.is_some(), |x| attr.tag = Some(x), |a| a.span, "tag")
+    } else if input.peek(kw::impl_from) {
+        return parse_trait_instruction_param_inner::<kw::impl_from, ()>(input, |_, _| Ok(()), attr.impl_from, |_| attr.impl_from = true, |a| a.span, "impl_from")
+    } else if input.peek(kw::patch) {
+        return parse_trait_instruction_param_inner::<kw::patch, ()>(input, |_, _| Ok(()), attr.patch, |_| attr.patch = true, |a| a.span, "patch")
+    } else if input.peek(kw::diff) {
+        return parse_trait_instruction_param_inner::<kw::diff, ()>(input, |_, _| Ok(()), attr.diff, |_| attr.diff = true, |a| a.span, "diff")
+    } else if input.peek(kw::error_paths) {
+        return parse_trait_instruction_param_inner::<kw::error_paths, ()>(input, |_, _| Ok(()), attr.error_paths, |_| attr.error_paths = true, |a| a.span, "error_paths")
+    } else if input.peek(kw::collect_errors) {
+        return parse_trait_instruction_param_inner::<kw::collect_errors, CollectErrors>(input, |x, _| x.parse(), attr.collect_errors.is_some(), |x| attr.collect_errors = Some(x), |a| a.span, "collect_errors")
+    } else if input.peek(Token![fn]) {
+        return parse_parenthesized_trait_instruction_param_inner::<Token![fn], StandaloneFn>(input, |c| c.parse(), attr.standalone_fn.is_some(), |x| attr.standalone_fn = Some(x), |a| a.span, "fn")
+    } else if input.peek(kw::rename_all) {
+        return parse_parenthesized_trait_instruction_param_inner::<kw::rename_all, RenameRule>(input, |c| c.parse(), attr.rename_all.is_some(), |x| attr.rename_all = Some(x), |a| a.span, "rename_all")
     }
 
     Ok(true)
 }
 
+#[derive(Clone)]
+pub(crate) enum RenameRule {
+    Template(String),
+    Lowercase,
+    Uppercase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+}
+
+const RENAME_RULES: [&str; 6] = ["lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE"];
+
+impl RenameRule {
+    pub(crate) fn apply(&self, name: &str) -> String {
+        let words = || {
+            let mut words: Vec<String> = vec![];
+            let mut prev_lower = false;
+            for ch in name.chars() {
+                if ch == '_' {
+                    words.push(String::new());
+                } else if words.is_empty() || (ch.is_uppercase() && prev_lower) {
+                    words.push(ch.to_string());
+                } else {
+                    words.last_mut().unwrap().push(ch);
+                }
+                prev_lower = ch.is_lowercase() || ch.is_numeric();
+            }
+            words.into_iter().filter(|x| !x.is_empty())
+        };
+        let capitalize = |word: String| {
+            let mut chars = word.chars();
+            chars.next().map(|x| x.to_uppercase().chain(chars.flat_map(|x| x.to_lowercase())).collect()).unwrap_or_default()
+        };
+
+        match self {
+            RenameRule::Template(template) => template.replace("{}", name),
+            RenameRule::Lowercase => name.to_lowercase(),
+            RenameRule::Uppercase => name.to_uppercase(),
+            RenameRule::PascalCase => words().map(capitalize).collect(),
+            RenameRule::CamelCase => words().enumerate().map(|(i, x)| if i == 0 { x.to_lowercase() } else { capitalize(x) }).collect(),
+            RenameRule::SnakeCase => words().map(|x| x.to_lowercase()).collect::<Vec<_>>().join("_"),
+            RenameRule::ScreamingSnakeCase => words().map(|x| x.to_uppercase()).collect::<Vec<_>>().join("_"),
+        }
+    }
+}
+
+impl Parse for RenameRule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit: syn::LitStr = input.parse()?;
+        let value = lit.value();
+        match value.as_str() {
+            "lowercase" => Ok(RenameRule::Lowercase),
+            "UPPERCASE" => Ok(RenameRule::Uppercase),
+            "PascalCase" => Ok(RenameRule::PascalCase),
+            "camelCase" => Ok(RenameRule::CamelCase),
+            "snake_case" => Ok(RenameRule::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnakeCase),
+            _ if value.contains("{}") => Ok(RenameRule::Template(value)),
+            _ => Err(Error::new(lit.span(), format!("Rename rule '{}' is not supported. Supported rules are: {}, or a template containing '{{}}'", value, RENAME_RULES.join(", ")))),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct CollectErrors {
+    pub collector_ty: Option<TypePath>,
+}
+
+impl Parse for CollectErrors {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let collector_ty = if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse::<syn::Path>()?.into())
+        } else { None };
+
+        Ok(CollectErrors { collector_ty })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct StandaloneFn {
+    pub vis: Visibility,
+    pub ident: Ident,
+}
+
+impl Parse for StandaloneFn {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(StandaloneFn {
+            vis: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct WithArg {
+    pub ident: Ident,
+    pub ty: syn::Type,
+}
+
+impl Parse for WithArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        Ok(WithArg { ident, ty: input.parse()? })
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct InitData {
     pub ident: Ident,
@@ -710,6 +915,7 @@ pub(crate) struct GhostData {
     pub child_path: Option<ChildPath>,
     pub ghost_ident: GhostIdent,
     pub action: TokenStream,
+    pub required_ty: Option<syn::Type>,
 }
 
 #[derive(Clone)]
@@ -767,7 +973,7 @@ impl Parse for GhostData {
             input.parse::<Token![@]>()?;
             child_path
         } else { None };
-        let ghost_ident = if input.peek2(Token![:]) {
+        let ghost_ident = if input.peek2(Token![:]) || input.peek2(Token![as]) {
             GhostIdent::Member(input.parse()?)
         } else if input.peek2(Brace) {
             let ident: Ident = input.parse()?;
@@ -783,9 +989,16 @@ impl Parse for GhostData {
             GhostIdent::Destruction(quote!(#ident (#destr)))
         };
 
+        if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            let required_ty = input.parse()?;
+            let ghost_ident_tokens = ghost_ident.get_ident().to_token_stream();
+            return Ok(GhostData { child_path, ghost_ident, action: ghost_ident_tokens, required_ty: Some(required_ty) })
+        }
+
         input.parse::<Token![:]>()?;
 
-        Ok(GhostData { child_path, ghost_ident, action: try_parse_action(input).map(|x| x.unwrap())? })
+        Ok(GhostData { child_path, ghost_ident, action: try_parse_action(input).map(|x| x.unwrap())?, required_ty: None })
     }
 }
 
@@ -803,6 +1016,117 @@ impl Parse for WhereAttr {
     }
 }
 
+pub(crate) struct RemoteAttr {
+    pub ty: TypePath,
+}
+
+impl Parse for RemoteAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(RemoteAttr {
+            ty: input.parse::<syn::Path>()?.into(),
+        })
+    }
+}
+
+pub(crate) struct ConvertInput {
+    pub src: TokenStream,
+    pub fallible: bool,
+    pub path: syn::Path,
+    pub fields: Vec<ConvertField>,
+    pub update: Option<TokenStream>,
+}
+
+pub(crate) struct ConvertField {
+    pub member: Member,
+    pub action: Option<TokenStream>,
+}
+
+impl Parse for ConvertInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut src: Vec<TokenTree> = vec![];
+        while !input.peek(Token![=>]) {
+            if input.is_empty() {
+                return Err(Error::new(input.span(), "Expected '=>' followed by the target type."));
+            }
+            src.push(input.parse()?);
+        }
+        if src.is_empty() {
+            return Err(Error::new(input.span(), "Expected source expression."));
+        }
+        input.parse::<Token![=>]>()?;
+
+        let fallible = input.peek(Token![?]);
+        if fallible {
+            input.parse::<Token![?]>()?;
+        }
+
+        let path = input.parse()?;
+        let content;
+        braced!(content in input);
+
+        let mut fields = vec![];
+        let mut update = None;
+        while !content.is_empty() {
+            if content.peek(Token![..]) {
+                content.parse::<Token![..]>()?;
+                update = Some(content.parse()?);
+                break;
+            }
+            let member = content.parse()?;
+            let action = if content.peek(Token![:]) {
+                content.parse::<Token![:]>()?;
+                Some(content.parse::<CommaDelimitedTokenStream>()?.token_stream)
+            } else { None };
+            fields.push(ConvertField { member, action });
+            if content.is_empty() { break; }
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(ConvertInput { src: TokenStream::from_iter(src), fallible, path, fields, update })
+    }
+}
+
+pub(crate) struct StrAttr {
+    pub span: Span,
+    pub err_ty: Option<TypePath>,
+    pub case_insensitive: bool,
+    pub rename_all: Option<RenameRule>,
+}
+
+impl Parse for StrAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = StrAttr { span: input.span(), err_ty: None, case_insensitive: false, rename_all: None };
+
+        if input.is_empty() {
+            return Ok(attr);
+        }
+
+        if !input.peek(Token![|]) && !input.peek(kw::case_insensitive) && !input.peek(kw::rename_all) {
+            attr.err_ty = Some(input.parse::<syn::Path>()?.into());
+        }
+
+        if input.peek(Token![|]) {
+            input.parse::<Token![|]>()?;
+        }
+
+        while parse_str_instruction_param(input, &mut attr)? {}
+
+        Ok(attr)
+    }
+}
+
+fn parse_str_instruction_param(input: &syn::parse::ParseBuffer, attr: &mut StrAttr) -> Result<bool> {
+    if input.peek(kw::case_insensitive) {
+        return parse_trait_instruction_param_inner::<kw::case_insensitive, ()>(input, |_, _| Ok(()), attr.case_insensitive, |_| attr.case_insensitive = true, |a| a.span, "case_insensitive")
+    } else if input.peek(kw::rename_all) {
+        return parse_parenthesized_trait_instruction_param_inner::<kw::rename_all, RenameRule>(input, |c| c.parse(), attr.rename_all.is_some(), |x| attr.rename_all = Some(x), |a| a.span, "rename_all")
+    } else if !input.is_empty() {
+        Err(input.error("Expected 'case_insensitive' or 'rename_all(...)' instruction parameter."))?
+    }
+
+    Ok(false)
+}
+
 pub(crate) struct ChildParentsAttr {
     pub container_ty: Option<TypePath>,
     pub child_parents: Punctuated<ChildParentData, Token![,]>,
@@ -847,7 +1171,7 @@ pub(crate) struct MemberAttr {
     pub attr: MemberAttrCore,
     pub fallible: bool,
     pub original_instr: String,
-    applicable_to: ApplicableTo,
+    pub applicable_to: ApplicableTo,
 }
 
 #[derive(Clone)]
@@ -855,18 +1179,82 @@ pub(crate) struct MemberAttrCore {
     pub container_ty: Option<TypePath>,
     pub member: Option<Member>,
     pub action: Option<TokenStream>,
+    pub map_err: Option<TokenStream>,
+    pub skip_if: Option<TokenStream>,
+    pub nested: bool,
+    /// Set by 'patch = false', which maps the member as is, even if the trait instruction uses 'patch'.
+    pub skip_patch: bool,
 }
 
 impl Parse for MemberAttrCore {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(MemberAttrCore {
-            container_ty: try_parse_container_ident(input, false),
-            member: try_parse_optional_ident(input),
-            action: try_parse_action(input)?,
-        })
+        MemberAttrCore::parse_inner(input, false)
     }
 }
 
+impl MemberAttrCore {
+    /// 'nested = [bool]' and 'patch = [bool]' are only recognized by 'into_existing' member instructions.
+    fn parse_inner(input: ParseStream, allow_flags: bool) -> Result<Self> {
+        let container_ty = try_parse_container_ident(input, false);
+        let (mut nested, mut patch) = (false, true);
+        let has_flags = allow_flags && parse_flags(input, &mut nested, &mut patch)?;
+        let member = if has_flags { None } else { try_parse_optional_ident(input) };
+        if allow_flags {
+            parse_flags(input, &mut nested, &mut patch)?;
+        }
+        let skip_if = if input.peek(kw::skip_if) && input.peek2(Token![=]) {
+            input.parse::<kw::skip_if>()?;
+            input.parse::<Token![=]>()?;
+            let skip_if = input.parse::<CommaDelimitedTokenStream>()?.token_stream;
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+            Some(skip_if)
+        } else { None };
+        let action = if peek_map_err(input) { None } else { try_parse_action(input)? };
+        let map_err = if peek_map_err(input) {
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+            input.parse::<kw::map_err>()?;
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<CommaDelimitedTokenStream>()?.token_stream)
+        } else { None };
+
+        Ok(MemberAttrCore { container_ty, member, action, map_err, skip_if, nested, skip_patch: !patch })
+    }
+}
+
+/// Parses 'nested = [bool]' and 'patch = [bool]' in any order, returns true if any of them was found.
+fn parse_flags(input: ParseStream, nested: &mut bool, patch: &mut bool) -> Result<bool> {
+    let mut found = false;
+    loop {
+        let flag = if input.peek(kw::nested) && input.peek2(Token![=]) {
+            input.parse::<kw::nested>()?;
+            &mut *nested
+        } else if input.peek(kw::patch) && input.peek2(Token![=]) {
+            input.parse::<kw::patch>()?;
+            &mut *patch
+        } else {
+            return Ok(found);
+        };
+        input.parse::<Token![=]>()?;
+        *flag = input.parse::<LitBool>()?.value;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        found = true;
+    }
+}
+
+fn peek_map_err(input: ParseStream) -> bool {
+    let fork = input.fork();
+    if fork.peek(Token![,]) {
+        fork.parse::<Token![,]>().unwrap();
+    }
+    fork.peek(kw::map_err) && fork.peek2(Token![=])
+}
+
 #[derive(Clone)]
 pub(crate) struct ParentAttr {
     pub container_ty: Option<TypePath>,
@@ -1098,6 +1486,43 @@ impl Parse for VariantTypeHintAttr {
     }
 }
 
+#[derive(Clone)]
+pub(crate) struct MapOptAttr {
+    pub container_ty: Option<TypePath>,
+}
+
+impl Parse for MapOptAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MapOptAttr { container_ty: try_parse_container_ident(input, true) })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct MapItemsAttr {
+    pub container_ty: Option<TypePath>,
+    pub keys: bool,
+    pub values: bool,
+}
+
+impl Parse for MapItemsAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let container_ty = try_parse_container_ident(input, !input.peek(kw::keys) && !input.peek(kw::values));
+
+        let mut attr = MapItemsAttr { container_ty, keys: false, values: false };
+
+        let parts: Punctuated<Ident, Token![,]> = Punctuated::parse_terminated(input)?;
+        for part in parts {
+            match part.to_string().as_str() {
+                "keys" => attr.keys = true,
+                "values" => attr.values = true,
+                str => return Err(Error::new(part.span(), format!("#[map_items] of '{}' is not supported. Supported options are: keys, values", str))),
+            }
+        }
+
+        Ok(attr)
+    }
+}
+
 pub(crate) fn get_data_type_attrs(input: &[Attribute]) -> Result<(DataTypeAttrs, bool)> {
     let mut bark = true;
 
@@ -1171,6 +1596,10 @@ pub(crate) fn get_data_type_attrs(input: &[Attribute]) -> Result<(DataTypeAttrs,
             DataTypeInstruction::Ghosts(attr) => attrs.ghosts_attrs.push(attr),
             DataTypeInstruction::Where(attr) => attrs.where_attrs.push(attr),
             DataTypeInstruction::ChildParents(attr) => attrs.child_parents_attrs.push(attr),
+            DataTypeInstruction::Remote(attr) => attrs.remote_attrs.push(attr),
+            DataTypeInstruction::FromStr(attr) => attrs.from_str_attrs.push(attr),
+            DataTypeInstruction::Display(attr) => attrs.display_attrs.push(attr),
+            DataTypeInstruction::Transparent => attrs.transparent = true,
             DataTypeInstruction::AllowUnknown | DataTypeInstruction::Unrecognized => (),
             _ => attrs.error_instrs.push(instr),
         };
@@ -1235,6 +1664,8 @@ pub(crate) fn get_member_attrs(input: SynDataTypeMember, bark: bool) -> Result<M
             MemberInstruction::SkipRepeat => attrs.skip_repeat = true,
             MemberInstruction::StopRepeat => attrs.stop_repeat = true,
             MemberInstruction::VariantTypeHint(attr) => attrs.type_hint_attrs.push(attr),
+            MemberInstruction::MapOpt(attr) => attrs.map_opt_attrs.push(attr),
+            MemberInstruction::MapItems(attr) => attrs.map_items_attrs.push(attr),
             MemberInstruction::Unrecognized => (),
             _ => attrs.error_instrs.push(instr),
         };
@@ -1246,6 +1677,10 @@ fn parse_data_type_instruction(instr: &Ident, input: TokenStream, own_instr: boo
     let instr_str = &instr.to_token_stream().to_string();
     match instr_str.as_ref() {
         "allow_unknown" if own_instr => Ok(DataTypeInstruction::AllowUnknown),
+        "transparent" if own_instr => Ok(DataTypeInstruction::Transparent),
+        "remote" if own_instr => Ok(DataTypeInstruction::Remote(syn::parse2(input)?)),
+        "from_str" if own_instr => Ok(DataTypeInstruction::FromStr(syn::parse2(input)?)),
+        "display" if own_instr => Ok(DataTypeInstruction::Display(syn::parse2(input)?)),
         "owned_into" | "ref_into" | "into" | "from_owned" | "from_ref" | "from" | "map_owned" | "map_ref" | "map" | "owned_into_existing" | "ref_into_existing" | "into_existing" => Ok(DataTypeInstruction::Map(TraitAttr {
             core: syn::parse2(input)?,
             fallible: false,
@@ -1270,6 +1705,23 @@ fn parse_data_type_instruction(instr: &Ident, input: TokenStream, own_instr: boo
                 appl_ref_into_existing(instr_str),
             ],
         })),
+        "async_owned_try_into" | "async_ref_try_into" | "async_try_into" | "async_try_from_owned" | "async_try_from_ref" | "async_try_from" => {
+            let mut core: TraitAttrCore = syn::parse2(input)?;
+            core.is_async = true;
+            let instr_str = instr_str.trim_start_matches("async_");
+            Ok(DataTypeInstruction::Map(TraitAttr {
+                core,
+                fallible: true,
+                applicable_to: [
+                    appl_owned_into(instr_str),
+                    appl_ref_into(instr_str),
+                    appl_from_owned(instr_str),
+                    appl_from_ref(instr_str),
+                    false,
+                    false,
+                ],
+            }))
+        },
         "ghosts" | "ghosts_ref" | "ghosts_owned" => Ok(DataTypeInstruction::Ghosts(GhostsAttr {
             attr: syn::parse2(input)?,
             applicable_to: [
@@ -1305,7 +1757,7 @@ fn parse_member_instruction(instr: &Ident, input: TokenStream, own_instr: bool,
     let instr_str = &instr.to_string();
     match instr_str.as_ref() {
         "owned_into" | "ref_into" | "into" | "from_owned" | "from_ref" | "from" | "map_owned" | "map_ref" | "map" | "owned_into_existing" | "ref_into_existing" | "into_existing" => Ok(MemberInstruction::Map(MemberAttr {
-            attr: syn::parse2(input)?,
+            attr: (|x: ParseStream| MemberAttrCore::parse_inner(x, appl_owned_into_existing(instr_str) || appl_ref_into_existing(instr_str))).parse2(input)?,
             fallible: false,
             original_instr: instr_str.clone(),
             applicable_to: [
@@ -1361,6 +1813,8 @@ fn parse_member_instruction(instr: &Ident, input: TokenStream, own_instr: bool,
         "skip_repeat" => Ok(MemberInstruction::SkipRepeat),
         "stop_repeat" => Ok(MemberInstruction::StopRepeat),
         "type_hint" => Ok(MemberInstruction::VariantTypeHint(syn::parse2(input)?)),
+        "map_opt" if own_instr => Ok(MemberInstruction::MapOpt(syn::parse2(input)?)),
+        "map_items" if own_instr => Ok(MemberInstruction::MapItems(syn::parse2(input)?)),
         "children" if bark => Ok(MemberInstruction::Misnamed { instr: "children", span: instr.span(), guess_name: "child", own: own_instr }),
         "child_parents" if bark => Ok(MemberInstruction::Misnamed { instr: "child_parents", span: instr.span(), guess_name: "child", own: own_instr }),
         "where_clause" if bark => Ok(MemberInstruction::Misplaced { instr: "where_clause", span: instr.span(), own: own_instr }),
@@ -1441,7 +1895,7 @@ fn peek_container_path(input: ParseStream, can_be_empty: bool) -> bool {
 }
 
 fn peek_ghost_field_name(input: ParseStream) -> bool {
-    peek_member(input) && (input.peek2(Token![:]) || input.peek2(Brace) || input.peek2(Paren))
+    peek_member(input) && (input.peek2(Token![:]) || input.peek2(Token![as]) || input.peek2(Brace) || input.peek2(Paren))
 }
 
 #[cfg(feature = "syn")]
@@ -1495,6 +1949,10 @@ fn add_as_type_attrs(input: &syn::Field, attr: AsAttr, attrs: &mut Vec<MemberAtt
             container_ty: attr.container_ty.clone(),
             member: attr.member.clone(),
             action: Some(quote!(~ as #this_ty)),
+            map_err: None,
+            skip_if: None,
+            nested: false,
+            skip_patch: false,
         },
         fallible: false,
         original_instr: "as_type".into(),
@@ -1505,6 +1963,10 @@ fn add_as_type_attrs(input: &syn::Field, attr: AsAttr, attrs: &mut Vec<MemberAtt
             container_ty: attr.container_ty,
             member: attr.member,
             action: Some(quote!(~ as #that_ty)),
+            map_err: None,
+            skip_if: None,
+            nested: false,
+            skip_patch: false,
         },
         fallible: false,
         original_instr: "as_type".into(),
@@ -2,38 +2,122 @@ use std::{collections::HashMap, iter::Peekable, slice::Iter};
 
 use crate::{
     ast::{DataType, DataTypeMember, Enum, Field, Struct, Variant},
-    attr::{ApplicableAttr, ChildParentData, ChildPath, DataTypeAttrs, GhostData, GhostIdent, Kind, MemberAttrCore, ParentChildField, TraitAttrCore, TypeHint},
+    attr::{ApplicableAttr, ChildParentData, ChildPath, CollectErrors, ConvertInput, DataTypeAttrs, GhostData, GhostIdent, Kind, LitAttr, MapItemsAttr, MemberAttrCore, ParentChildField, PatAttr, RenameRule, StandaloneFn, StrAttr, TraitAttrCore, TypeHint, WithArg},
     validate::validate,
 };
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 
 #[cfg(feature = "syn2")]
 use syn2 as syn;
 
 use syn::{
-    parse_quote, Data, DeriveInput, Error, GenericArgument, GenericParam, Index, Lifetime,
-    Member::{self, Named, Unnamed}, Result
+    parse::{ParseStream, Parser}, parse_quote, spanned::Spanned, Data, DeriveInput, Error, GenericArgument, GenericParam, Generics, Ident, Index, Lifetime,
+    Member::{self, Named, Unnamed}, PathArguments, Result, Type, Visibility
 };
 
 pub fn derive(node: &DeriveInput) -> Result<TokenStream> {
+    derive_item(node, false)
+}
+
+/// 'in_mapping' is set for declarations of 'o2o::mapping!', that are not emitted.
+fn derive_item(node: &DeriveInput, in_mapping: bool) -> Result<TokenStream> {
     match &node.data {
         Data::Struct(data) => {
             let input = Struct::from_syn(node, data)?;
             let input = DataType::Struct(&input);
-            validate(&input)?;
+            validate(&input, in_mapping)?;
             Ok(data_type_impl(input))
         },
         Data::Enum(data) => {
             let input = Enum::from_syn(node, data)?;
             let input = DataType::Enum(&input);
-            validate(&input)?;
+            validate(&input, in_mapping)?;
             Ok(data_type_impl(input))
         },
         _ => Err(Error::new_spanned(node, "#[derive(o2o)] only supports structs and enums.")),
     }
 }
 
+pub fn expand_mapping(input: TokenStream) -> Result<TokenStream> {
+    let items = (|input: ParseStream| {
+        let mut items: Vec<DeriveInput> = vec![];
+        while !input.is_empty() {
+            items.push(input.parse()?);
+        }
+        Ok(items)
+    }).parse2(input)?;
+
+    let mut output = TokenStream::new();
+    let mut error: Option<Error> = None;
+
+    for item in &items {
+        match derive_item(item, true) {
+            Ok(tokens) => output.extend(tokens),
+            Err(err) => match &mut error {
+                Some(error) => error.combine(err),
+                None => error = Some(err),
+            },
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(output),
+    }
+}
+
+pub fn expand_convert(input: TokenStream) -> Result<TokenStream> {
+    let input: ConvertInput = syn::parse2(input)?;
+
+    let value = quote!(o2o_value);
+    let src = &input.src;
+    let path = &input.path;
+
+    let fields = input.fields.iter().map(|f| {
+        let member = &f.member;
+        let field_path = quote!(#value.#member);
+        let right_side = match &f.action {
+            Some(action) => replace_tilde_or_at_in_expr(action, Some(&value), Some(&field_path)),
+            None => field_path,
+        };
+        quote!(#member: #right_side,)
+    });
+    let update = input.update.as_ref().map(|x| {
+        let x = replace_tilde_or_at_in_expr(x, Some(&value), None);
+        quote!(..#x)
+    });
+
+    let init = quote!({
+        let #value = #src;
+        #path { #(#fields)* #update }
+    });
+
+    Ok(match input.fallible {
+        true => quote!(::core::result::Result::Ok(#init)),
+        false => init,
+    })
+}
+
+pub fn derive_shape(node: &DeriveInput) -> Result<TokenStream> {
+    match &node.data {
+        Data::Struct(data) => {
+            let ident = &node.ident;
+            let (impl_gens, ty_gens, where_clause) = node.generics.split_for_impl();
+            let fields = data.fields.iter().enumerate().map(|(idx, f)| match &f.ident {
+                Some(ident) => shape_field_name(&Named(ident.clone())),
+                None => idx.to_string(),
+            });
+            Ok(quote! {
+                impl #impl_gens o2o::traits::Shape for #ident #ty_gens #where_clause {
+                    const FIELDS: &'static [&'static str] = &[#(#fields),*];
+                }
+            })
+        },
+        _ => Err(Error::new_spanned(node, "#[derive(Shape)] only supports structs.")),
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum ImplType {
     Struct,
@@ -56,6 +140,71 @@ struct ImplContext<'a> {
     src_ty: &'a TokenStream,
     has_post_init: bool,
     fallible: bool,
+    /// Renders comparisons instead of assignments, see [quote_diff_trait].
+    diff: bool,
+}
+
+impl<'a> ImplContext<'a> {
+    fn new(input: &'a DataType<'a>, impl_type: ImplType, struct_attr: &'a TraitAttrCore, kind: Kind, fallible: bool, ty: &'a TokenStream) -> Self {
+        let (dst_ty, src_ty) = if kind.is_from() { (ty, &struct_attr.ty.path) } else { (&struct_attr.ty.path, ty) };
+        ImplContext { input, impl_type, struct_attr, kind, dst_ty, src_ty, has_post_init: false, fallible, diff: false }
+    }
+
+    fn error_ty(&self) -> TokenStream {
+        let err_ty = &self.struct_attr.err_ty.as_ref().unwrap().path;
+        match &self.struct_attr.collect_errors {
+            Some(CollectErrors { collector_ty: Some(collector_ty) }) => collector_ty.path.to_token_stream(),
+            Some(CollectErrors { collector_ty: None }) => quote!(o2o::traits::ConversionErrors<#err_ty>),
+            None if self.struct_attr.error_paths => quote!(o2o::traits::FieldError<#err_ty>),
+            None => err_ty.clone(),
+        }
+    }
+
+    fn this(&self) -> TokenStream {
+        // Standalone functions of remote types can't take 'self', so the source is passed as 'value'.
+        // Same goes for builders, where 'self' is the builder itself, and for 'From' implementations of 'into' instructions.
+        if self.kind.is_from() || self.struct_attr.impl_from || (self.struct_attr.standalone_fn.is_some() && self.input.get_attrs().remote_attr().is_some()) || !self.builder_ghosts().is_empty() {
+            quote!(value)
+        } else {
+            quote!(self)
+        }
+    }
+
+    /// The only field of a transparent struct, if the instruction type is the type of that field.
+    fn transparent_field(&self) -> Option<&Field> {
+        match self.input {
+            DataType::Struct(s) if s.attrs.transparent => s.fields.first()
+                .filter(|f| f.ty.as_ref().is_some_and(|ty| syn::parse_str(&self.struct_attr.ty.path_str).is_ok_and(|other| same_type(&parse_quote!(#ty), &other)))),
+            _ => None,
+        }
+    }
+
+    /// Ghost fields without default value, which have to be supplied through a builder.
+    fn builder_ghosts(&self) -> Vec<&GhostData> {
+        self.input.get_attrs().ghosts_attr(&self.struct_attr.ty, &self.kind).iter()
+            .flat_map(|x| &x.ghost_data)
+            .filter(|x| x.required_ty.is_some())
+            .collect()
+    }
+
+    fn rename(&self, member: &Member) -> Member {
+        match (member, &self.struct_attr.rename_all) {
+            (Named(ident), Some(rule)) => {
+                let name = ident.to_string();
+                let name = name.trim_start_matches("r#");
+                let renamed = rule.apply(name);
+                // Invalid names are reported by validation, they only get here for members that are mapped explicitly
+                match syn::parse_str::<Ident>(&renamed) {
+                    Ok(mut renamed) if renamed != name => {
+                        renamed.set_span(ident.span());
+                        Named(renamed)
+                    },
+                    _ => member.clone(),
+                }
+            },
+            _ => member.clone(),
+        }
+    }
 }
 
 struct ChildRenderContext<'a> {
@@ -87,101 +236,103 @@ enum VariantData<'a> {
 }
 
 fn data_type_impl(input: DataType) -> TokenStream {
-    let ty = input.get_ident().to_token_stream();
     let attrs = input.get_attrs();
+    let ty = attrs.remote_attr().map_or_else(|| input.get_ident().to_token_stream(), |x| x.ty.path.clone());
 
     let impl_type = match input {
         DataType::Struct(_) => ImplType::Struct,
         DataType::Enum(_) => ImplType::Enum,
     };
 
-    let impls = std::iter::empty().chain(attrs.iter_for_kind_core(&Kind::FromOwned, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::FromOwned,
-        dst_ty: &ty,
-        src_ty: &struct_attr.ty.path,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::FromOwned, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::FromOwned,
-        dst_ty: &ty,
-        src_ty: &struct_attr.ty.path,
-        has_post_init: false,
-        fallible: true,
-    })).chain(attrs.iter_for_kind_core(&Kind::FromRef, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::FromRef,
-        dst_ty: &ty,
-        src_ty: &struct_attr.ty.path,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::FromRef, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::FromRef,
-        dst_ty: &ty,
-        src_ty: &struct_attr.ty.path,
-        has_post_init: false,
-        fallible: true,
-    })).chain(attrs.iter_for_kind_core(&Kind::OwnedInto, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::OwnedInto,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::OwnedInto, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::OwnedInto,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: true,
-    })).chain(attrs.iter_for_kind_core(&Kind::RefInto, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::RefInto,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::RefInto, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::RefInto,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: true,
-    })).chain(attrs.iter_for_kind_core(&Kind::OwnedIntoExisting, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::OwnedIntoExisting,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::OwnedIntoExisting, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::OwnedIntoExisting,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: true,
-    })).chain(attrs.iter_for_kind_core(&Kind::RefIntoExisting, false).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::RefIntoExisting,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: false,
-    })).chain(attrs.iter_for_kind_core(&Kind::RefIntoExisting, true).map(|struct_attr| ImplContext {
-        input: &input, impl_type, struct_attr,
-        kind: Kind::RefIntoExisting,
-        dst_ty: &struct_attr.ty.path,
-        src_ty: &ty,
-        has_post_init: false,
-        fallible: true,
-    })).map(|mut ctx| quote_trait(&input, &mut ctx));
-
-    quote! { #(#impls)* }
+    let impls = std::iter::empty()
+        .chain(attrs.iter_for_kind_core(&Kind::FromOwned, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::FromOwned, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::FromOwned, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::FromOwned, true, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::FromRef, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::FromRef, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::FromRef, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::FromRef, true, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::OwnedInto, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::OwnedInto, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::OwnedInto, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::OwnedInto, true, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::RefInto, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::RefInto, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::RefInto, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::RefInto, true, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::OwnedIntoExisting, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::OwnedIntoExisting, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::OwnedIntoExisting, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::OwnedIntoExisting, true, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::RefIntoExisting, false).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::RefIntoExisting, false, &ty)))
+        .chain(attrs.iter_for_kind_core(&Kind::RefIntoExisting, true).map(|struct_attr| ImplContext::new(&input, impl_type, struct_attr, Kind::RefIntoExisting, true, &ty)))
+        .map(|mut ctx| quote_trait(&input, &mut ctx));
+
+    let str_impls = match input {
+        DataType::Enum(e) => {
+            let from_str = attrs.from_str_attrs.first().map(|attr| quote_from_str_trait(e, attr, &ty));
+            let display = attrs.display_attrs.first().map(|attr| quote_display_trait(e, attr, &ty));
+            quote!(#from_str #display)
+        },
+        DataType::Struct(_) => TokenStream::new(),
+    };
+
+    quote! { #(#impls)* #str_impls }
+}
+
+fn variant_str_lit(v: &Variant, attr: &StrAttr) -> TokenStream {
+    if let Some(lit) = v.attrs.str_lit() {
+        return lit.tokens.clone();
+    }
+    let name = v.ident.to_string();
+    let name = name.trim_start_matches("r#");
+    let name = attr.rename_all.as_ref().map_or(name.to_string(), |rule| rule.apply(name));
+    quote!(#name)
+}
+
+fn quote_from_str_trait(input: &Enum, attr: &StrAttr, ty: &TokenStream) -> TokenStream {
+    let (impl_gens, ty_gens, where_clause) = input.generics.split_for_impl();
+    let (err_ty, err) = match &attr.err_ty {
+        Some(err_ty) => {
+            let path = &err_ty.path;
+            let gens = &err_ty.generics;
+            (quote!(#path #gens), quote!(::core::convert::From::from(o2o::traits::ParseEnumError)))
+        },
+        None => (quote!(o2o::traits::ParseEnumError), quote!(o2o::traits::ParseEnumError)),
+    };
+
+    let arms = input.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let lit = variant_str_lit(v, attr);
+        if attr.case_insensitive {
+            quote!(s if s.eq_ignore_ascii_case(#lit) => Ok(#ty::#ident),)
+        } else {
+            quote!(#lit => Ok(#ty::#ident),)
+        }
+    });
+
+    quote! {
+        impl #impl_gens ::core::str::FromStr for #ty #ty_gens #where_clause {
+            type Err = #err_ty;
+            fn from_str(s: &str) -> ::core::result::Result<#ty #ty_gens, #err_ty> {
+                match s {
+                    #(#arms)*
+                    _ => Err(#err),
+                }
+            }
+        }
+    }
+}
+
+fn quote_display_trait(input: &Enum, attr: &StrAttr, ty: &TokenStream) -> TokenStream {
+    let (impl_gens, ty_gens, where_clause) = input.generics.split_for_impl();
+
+    let arms = input.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let lit = variant_str_lit(v, attr);
+        quote!(#ty::#ident => #lit,)
+    });
+
+    quote! {
+        impl #impl_gens ::core::fmt::Display for #ty #ty_gens #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    #(#arms)*
+                })
+            }
+        }
+    }
 }
 
 fn main_code_block(ctx: &ImplContext) -> TokenStream {
@@ -211,6 +362,7 @@ fn main_code_block_ok(ctx: &ImplContext) -> TokenStream {
     }
 
     let inner = match ctx.input {
+        DataType::Struct(s) if ctx.struct_attr.collect_errors.is_some() && !s.unit => return struct_collect_errors_block(s, ctx),
         DataType::Struct(s) => struct_main_code_block(s, ctx),
         DataType::Enum(e) => enum_main_code_block(e, ctx),
     };
@@ -223,6 +375,10 @@ fn main_code_block_ok(ctx: &ImplContext) -> TokenStream {
 }
 
 fn struct_main_code_block(input: &Struct, ctx: &ImplContext) -> TokenStream {
+    if let Some(f) = ctx.transparent_field() {
+        return transparent_main_code_block(input, f, ctx);
+    }
+
     let struct_init_block = struct_init_block(input, ctx);
 
     match ctx.kind {
@@ -247,22 +403,124 @@ fn enum_main_code_block(input: &Enum, ctx: &ImplContext) -> TokenStream {
 
     match ctx.kind {
         Kind::FromOwned | Kind::FromRef => {
-            let match_expr = if let Some(ts) = &ctx.struct_attr.match_expr { replace_tilde_or_at_in_expr(&ts.token_stream, Some(&quote!(value)), None) } else { quote!(value) };
+            let match_expr = match (&ctx.struct_attr.match_expr, &ctx.struct_attr.tag) {
+                (Some(ts), _) => replace_tilde_or_at_in_expr(&ts.token_stream, Some(&quote!(value)), None),
+                (None, Some(tag)) => tagged_match_expr(input, tag, ctx),
+                (None, None) => quote!(value),
+            };
             quote!(match #match_expr #enum_init_block)
         },
         Kind::OwnedInto | Kind::RefInto => {
-            let match_expr = if let Some(ts) = &ctx.struct_attr.match_expr { replace_tilde_or_at_in_expr(&ts.token_stream, Some(&quote!(self)), None) } else { quote!(self) };
+            let this = ctx.this();
+            let match_expr = if let Some(ts) = &ctx.struct_attr.match_expr { replace_tilde_or_at_in_expr(&ts.token_stream, Some(&this), None) } else { this };
             quote!(match #match_expr #enum_init_block)
         },
         Kind::OwnedIntoExisting | Kind::RefIntoExisting => enum_init_block,
     }
 }
 
-fn struct_init_block<'a>(input: &'a Struct, ctx: &ImplContext) -> TokenStream {
+fn transparent_main_code_block(input: &Struct, f: &Field, ctx: &ImplContext) -> TokenStream {
+    let this = ctx.this();
+    let member = &f.member;
+    let action = match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty) {
+        Some(ApplicableAttr::Field(MemberAttrCore { action: Some(action), .. })) => Some(action),
+        _ => None,
+    };
+
+    match ctx.kind {
+        Kind::FromOwned | Kind::FromRef => {
+            let dst = ctx.dst_ty;
+            let value = action.map_or_else(|| if ctx.kind.is_ref() { quote!(*value) } else { quote!(value) }, |x| replace_tilde_or_at_in_expr(x, Some(&this), Some(&this)));
+            if input.named_fields { quote!(#dst { #member: #value }) } else { quote!(#dst(#value)) }
+        },
+        Kind::OwnedInto | Kind::RefInto => action.map_or(quote!(#this.#member), |x| replace_tilde_or_at_in_expr(x, Some(&this), Some(&quote!(#this.#member)))),
+        Kind::OwnedIntoExisting | Kind::RefIntoExisting => {
+            let value = action.map_or(quote!(#this.#member), |x| replace_tilde_or_at_in_expr(x, Some(&this), Some(&quote!(#this.#member))));
+            quote!(*other = #value;)
+        },
+    }
+}
+
+fn struct_collect_errors_block(input: &Struct, ctx: &ImplContext) -> TokenStream {
+    let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+    let collector_ty = ctx.error_ty();
+    let ty = &ctx.struct_attr.ty;
+    let type_hint = ctx.struct_attr.type_hint;
+
+    // Child and parent instructions are rejected by validation, so every field maps to exactly one line
+    let fields = input.fields.iter()
+        .filter(|f| match f.attrs.ghost(ty, &ctx.kind) {
+            Some(ghost_attr) => ctx.kind.is_from() && ghost_attr.action.is_some(),
+            None => true,
+        })
+        .enumerate()
+        .filter_map(|(idx, f)| {
+            let mut line = render_struct_line_inner(f, ctx, type_hint, idx, None)?;
+            line.map_errs(member_map_err(f, ctx), ctx);
+            Some(line)
+        });
+    let ghosts = input.attrs.ghosts_attr(ty, &ctx.kind).filter(|_| !ctx.kind.is_from()).into_iter()
+        .flat_map(|x| &x.ghost_data)
+        .map(|x| ghost_struct_line(x, ctx));
+
+    let mut bindings: Vec<TokenStream> = vec![];
+    let mut fragments: Vec<TokenStream> = fields.chain(ghosts).enumerate().map(|(idx, mut line)| {
+        let binding = format_ident!("o2o_f{}", idx);
+        let value = match line.try_operand() {
+            Some(right_side) => {
+                let field_name = match &line.target {
+                    LineTarget::Field(name) => name.to_string().trim_start_matches("r#").to_string(),
+                    _ => idx.to_string(),
+                };
+                bindings.push(quote! {
+                    let #binding = match #right_side {
+                        ::core::result::Result::Ok(x) => ::core::option::Option::Some(x),
+                        ::core::result::Result::Err(e) => {
+                            <#collector_ty as o2o::traits::ErrorCollector<#err_ty>>::push(&mut errors, #field_name, ::core::convert::From::from(e));
+                            ::core::option::Option::None
+                        }
+                    };
+                });
+                quote!(#binding.unwrap())
+            },
+            None => {
+                let right_side = &line.rhs;
+                bindings.push(quote!(let #binding = #right_side;));
+                binding.to_token_stream()
+            }
+        };
+        line.replace_rhs(value);
+        line.into_token_stream()
+    }).collect();
+
+    if let Some(update) = &ctx.struct_attr.update {
+        let a = quote_action(&update.token_stream, None, ctx);
+        fragments.push(quote!(..#a))
+    }
+
+    let dst = if ctx.kind.is_from() || !ctx.struct_attr.ty.nameless_tuple { ctx.dst_ty.clone() } else { TokenStream::new() };
+    let init = wrap_struct_fragments(fragments, input.named_fields, ctx, type_hint);
+
+    quote! {
+        let mut errors: #collector_ty = ::core::default::Default::default();
+        #(#bindings)*
+        if !o2o::traits::ErrorCollector::is_empty(&errors) {
+            return Err(errors);
+        }
+        Ok(#dst #init)
+    }
+}
+
+fn struct_init_block(input: &Struct, ctx: &ImplContext) -> TokenStream {
     if (!ctx.kind.is_from() && ctx.struct_attr.type_hint == TypeHint::Unit) || (ctx.kind.is_from() && input.unit) {
         return TokenStream::new();
     }
 
+    let fields = struct_init_fields(input, ctx);
+    struct_init_block_inner(&mut fields.iter().peekable(), input.named_fields, ctx, None)
+}
+
+fn struct_init_fields<'a>(input: &'a Struct, ctx: &ImplContext) -> Vec<FieldContainer<'a>> {
     let mut group_paths = HashMap::<String, usize>::new();
     group_paths.insert("".into(), 0);
 
@@ -297,8 +555,7 @@ fn struct_init_block<'a>(input: &'a Struct, ctx: &ImplContext) -> TokenStream {
         }));
 
     fields.sort_by(|a, b| a.gr_idx.cmp(&b.gr_idx));
-
-    struct_init_block_inner(&mut fields.iter().peekable(), input.named_fields, ctx, None)
+    fields
 }
 
 fn struct_init_block_inner(
@@ -307,6 +564,16 @@ fn struct_init_block_inner(
     ctx: &ImplContext,
     field_ctx: Option<(&ChildPath, Option<&ChildRenderContext>, usize)>
 ) -> TokenStream
+{
+    let (fragments, type_hint) = struct_init_fragments(members, ctx, field_ctx);
+    wrap_struct_fragments(fragments, named_fields, ctx, type_hint)
+}
+
+fn struct_init_fragments(
+    members: &mut Peekable<Iter<FieldContainer>>,
+    ctx: &ImplContext,
+    field_ctx: Option<(&ChildPath, Option<&ChildRenderContext>, usize)>
+) -> (Vec<TokenStream>, TypeHint)
 {
     let type_hint = ctx.struct_attr.type_hint;
     let type_hint = field_ctx.map_or(type_hint, |x|x.1.map_or(type_hint, |x|x.type_hint));
@@ -383,6 +650,10 @@ fn struct_init_block_inner(
         fragments.push(quote!(..#a))
     }
 
+    (fragments, type_hint)
+}
+
+fn wrap_struct_fragments(fragments: Vec<TokenStream>, named_fields: bool, ctx: &ImplContext, type_hint: TypeHint) -> TokenStream {
     if ctx.has_post_init || ctx.kind.is_into_existing() {
         return quote!(#(#fragments)*);
     }
@@ -461,13 +732,14 @@ fn variant_destruct_block(input: &Struct, ctx: &ImplContext) -> TokenStream {
                 .map(|x| {
                     let attr = x.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty);
 
-                    if !ctx.kind.is_from() || attr.is_none() {
+                    if !ctx.kind.is_from() {
                         let ident = &x.member;
                         quote!(#ident ,)
-                    } else if let Some(attr) = attr {
-                        let ident = attr.get_field_name_or(&x.member);
+                    } else {
+                        let other = ctx.rename(&x.member);
+                        let ident = attr.as_ref().map_or(&other, |attr| attr.get_field_name_or(&other));
                         quote!(#ident ,)
-                    } else { unreachable!("3") }
+                    }
                 }).collect(),
             TypeHint::Struct,
         ),
@@ -513,6 +785,7 @@ fn render_child_fragment<F: Fn() -> TokenStream>(
     if depth.is_none() || depth.unwrap() < child_path.child_path_str.len() - 1 {
         let new_depth = depth.map_or(0, |x|x+1);
         match ctx.kind {
+            Kind::OwnedInto | Kind::RefInto if ctx.has_post_init => render_existing_child(fields, ctx.input.named_fields(), ctx, (child_path, new_depth)),
             Kind::OwnedInto | Kind::RefInto => {
                 let mut child_parents = ctx.input.get_attrs().child_parents_attr(&ctx.struct_attr.ty).unwrap().child_parents.iter();
                 let child_data = child_parents.find(|child_data| child_data.check_match(child_path.get_child_path_str(Some(new_depth)))).unwrap();
@@ -584,7 +857,7 @@ fn struct_post_init(input: &DataType, ctx: &ImplContext) -> Option<TokenStream>
         }
     });
 
-    if fragments.is_empty() {
+    if fragments.is_empty() && !ctx.struct_attr.default_init {
         return None;
     }
     Some(quote!(#(#fragments)*))
@@ -592,17 +865,28 @@ fn struct_post_init(input: &DataType, ctx: &ImplContext) -> Option<TokenStream>
 
 fn render_parent(f: &Field, ctx: &ImplContext) -> TokenStream {
     let member = &f.member;
-    match (&ctx.kind, ctx.fallible) {
-        (Kind::OwnedIntoExisting, false) => quote!(self.#member.into_existing(other);),
-        (Kind::RefIntoExisting, false) => quote!((&(self.#member)).into_existing(other);),
-        (Kind::OwnedInto, false) => quote!(self.#member.into_existing(&mut obj);),
-        (Kind::RefInto, false) => quote!((&(self.#member)).into_existing(&mut obj);),
-        (Kind::OwnedIntoExisting, true) => quote!(self.#member.try_into_existing(other)?;),
-        (Kind::RefIntoExisting, true) => quote!((&(self.#member)).try_into_existing(other)?;),
-        (Kind::OwnedInto, true) => quote!(self.#member.try_into_existing(&mut obj)?;),
-        (Kind::RefInto, true) => quote!((&(self.#member)).try_into_existing(&mut obj)?;),
+    let this = ctx.this();
+    let rhs = match (&ctx.kind, ctx.fallible) {
+        (Kind::OwnedIntoExisting, false) if ctx.diff => quote!(changes.extend(#this.#member.diff_into(other))),
+        (Kind::RefIntoExisting, false) if ctx.diff => quote!(changes.extend((&(#this.#member)).diff_into(other))),
+        (Kind::OwnedIntoExisting, false) => quote!(#this.#member.into_existing(other)),
+        (Kind::RefIntoExisting, false) => quote!((&(#this.#member)).into_existing(other)),
+        (Kind::OwnedInto, false) => quote!(#this.#member.into_existing(&mut obj)),
+        (Kind::RefInto, false) => quote!((&(#this.#member)).into_existing(&mut obj)),
+        (Kind::OwnedIntoExisting, true) => quote!(#this.#member.try_into_existing(other)?),
+        (Kind::RefIntoExisting, true) => quote!((&(#this.#member)).try_into_existing(other)?),
+        (Kind::OwnedInto, true) => quote!(#this.#member.try_into_existing(&mut obj)?),
+        (Kind::RefInto, true) => quote!((&(#this.#member)).try_into_existing(&mut obj)?),
         _ => unreachable!("5"),
+    };
+    let mut line = StructLine::stmt(rhs);
+    if let Some(map_err) = &ctx.struct_attr.map_err {
+        line.map_err(map_err, false);
+    }
+    if ctx.struct_attr.error_paths {
+        line.error_path(&shape_field_name(member), true, ctx);
     }
+    line.into_token_stream()
 }
 
 fn render_child(
@@ -640,6 +924,13 @@ fn render_existing_child(
     struct_init_block_inner(fields, named_fields, ctx, Some((field_ctx.0, child_data.map(|x|x.into()).as_ref(), field_ctx.1)))
 }
 
+fn member_map_err<'a>(f: &'a Field, ctx: &'a ImplContext) -> Option<&'a TokenStream> {
+    match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty) {
+        Some(ApplicableAttr::Field(MemberAttrCore { map_err, .. })) => map_err.as_ref(),
+        _ => None,
+    }
+}
+
 fn render_struct_line(
     f: &Field,
     ctx: &ImplContext,
@@ -647,6 +938,225 @@ fn render_struct_line(
     idx: usize,
     parent_child: Option<&ParentChildField>
 ) -> TokenStream
+{
+    let Some(mut line) = render_struct_line_inner(f, ctx, hint, idx, parent_child) else { return TokenStream::new() };
+    let member_map_err = parent_child.is_none().then(|| member_map_err(f, ctx)).flatten();
+    let (skip_if, nested, has_action, skip_patch) = match parent_child {
+        None => match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty) {
+            Some(ApplicableAttr::Field(MemberAttrCore { skip_if, nested, action, skip_patch, .. })) if ctx.kind.is_into_existing() => (skip_if.as_ref(), *nested, action.is_some(), *skip_patch),
+            _ => (None, false, false, false),
+        },
+        Some(_) => (None, false, false, false),
+    };
+    // Nested updates of Option fields are conditional already, see IntoExisting implementations for Option.
+    // Members with skip conditions decide for themselves whether they are updated.
+    let patch = ctx.struct_attr.patch && ctx.kind.is_into_existing() && parent_child.is_none() && !nested && skip_if.is_none() && !skip_patch;
+    if !ctx.diff && !patch && !nested && skip_if.is_none() && member_map_err.is_none() && ctx.struct_attr.map_err.is_none() && !ctx.struct_attr.error_paths {
+        return line.into_token_stream();
+    }
+    let by_ref = ctx.kind == Kind::RefIntoExisting && !has_action;
+
+    if nested && !ctx.diff {
+        line = line.nested(by_ref, ctx.fallible);
+    }
+
+    line.map_errs(member_map_err, ctx);
+
+    if ctx.struct_attr.error_paths {
+        let ty = &ctx.struct_attr.ty;
+        let path = match parent_child {
+            Some(p) => format!("{}{}.{}", f.member_str, p.sub_path_tokens.to_string().replace(' ', ""), shape_field_name(&p.this_member)),
+            None if f.attrs.has_parent_attr(ty) => shape_field_name(&f.member),
+            None => {
+                let member = match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, ty) {
+                    Some(ApplicableAttr::Field(MemberAttrCore { member: Some(member), .. })) => shape_field_name(member),
+                    _ => shape_field_name(&ctx.rename(&f.member)),
+                };
+                match f.attrs.child(ty) {
+                    Some(child_attr) => format!("{}.{}", child_attr.get_child_path_str(None), member),
+                    None => member,
+                }
+            }
+        };
+        line.error_path(&path, nested || (parent_child.is_none() && f.attrs.has_parent_attr(ty)), ctx);
+    }
+
+    // Ref kinds can't move the value out of the Option, so it is borrowed and cloned
+    let patch_value = patch.then(|| match by_ref {
+        true => { let value = line.replace_rhs(quote!(v.clone())); quote!(&#value) },
+        false => line.replace_rhs(quote!(v)),
+    });
+    let mut line = match (ctx.diff, nested) {
+        (true, true) => line.nested_diff(by_ref),
+        (true, false) => line.diff(),
+        (false, _) => line.into_token_stream(),
+    };
+
+    if let Some(value) = patch_value {
+        line = quote!(if let Some(v) = #value { #line });
+    }
+
+    if let Some(skip_if) = skip_if {
+        let src = match (&f.member, ctx.impl_type.is_variant()) {
+            (Named(ident), true) => ident.to_token_stream(),
+            (Unnamed(index), true) => format_ident!("f{}", index.index).to_token_stream(),
+            (member, false) => {
+                let this = ctx.this();
+                quote!(#this.#member)
+            }
+        };
+        line = quote!(if !(#skip_if)(&#src) { #line });
+    }
+
+    line
+}
+
+/// Rendered struct line, kept as the place that receives the value and the value itself,
+/// so that the value can be wrapped or checked before the line is turned into tokens.
+struct StructLine {
+    target: LineTarget,
+    rhs: TokenStream,
+}
+
+enum LineTarget {
+    /// 'name: rhs,'
+    Field(TokenStream),
+    /// 'rhs,'
+    Positional,
+    /// 'root.path = rhs;'
+    Assign(TokenStream, TokenStream),
+    /// 'rhs;'
+    Stmt,
+}
+
+impl StructLine {
+    fn field(name: impl ToTokens, rhs: TokenStream) -> StructLine {
+        StructLine { target: LineTarget::Field(name.to_token_stream()), rhs }
+    }
+
+    fn positional(rhs: TokenStream) -> StructLine {
+        StructLine { target: LineTarget::Positional, rhs }
+    }
+
+    fn assign(root: TokenStream, path: impl ToTokens, rhs: TokenStream) -> StructLine {
+        StructLine { target: LineTarget::Assign(root, path.to_token_stream()), rhs }
+    }
+
+    fn stmt(rhs: TokenStream) -> StructLine {
+        StructLine { target: LineTarget::Stmt, rhs }
+    }
+
+    fn ends_with_try(&self) -> bool {
+        matches!(self.rhs.clone().into_iter().last(), Some(TokenTree::Punct(p)) if p.as_char() == '?')
+    }
+
+    /// Value without the trailing '?', if there is one.
+    fn try_operand(&self) -> Option<TokenStream> {
+        if !self.ends_with_try() {
+            return None;
+        }
+        let mut tokens: Vec<TokenTree> = self.rhs.clone().into_iter().collect();
+        tokens.pop();
+        Some(tokens.into_iter().collect())
+    }
+
+    fn map_err(&mut self, map_err: &TokenStream, force: bool) {
+        let rhs = match self.try_operand() {
+            Some(rhs) => rhs,
+            None if force => self.rhs.clone(),
+            None => return,
+        };
+        self.rhs = quote!(#rhs.map_err(#map_err)?);
+    }
+
+    /// Applies member level 'map_err' if there is one, trait level 'map_err' otherwise.
+    fn map_errs(&mut self, member_map_err: Option<&TokenStream>, ctx: &ImplContext) {
+        if !ctx.fallible {
+            return;
+        }
+        match (member_map_err, &ctx.struct_attr.map_err) {
+            (Some(map_err), _) => self.map_err(map_err, true),
+            (None, Some(map_err)) => self.map_err(map_err, false),
+            (None, None) => (),
+        }
+    }
+
+    /// Attaches 'path' to the error of a fallible value, i.e. the one ending with '?'.
+    /// Errors of nested conversions may carry a path of their own, so with 'nested' that path gets prefixed instead.
+    fn error_path(&mut self, path: &str, nested: bool, ctx: &ImplContext) {
+        let Some(rhs) = self.try_operand() else { return };
+        let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+        let error = if nested {
+            quote!(o2o::traits::IntoFieldError::<#err_ty>::into_field_error(e, #path))
+        } else {
+            quote!(o2o::traits::FieldError::<#err_ty>::new(#path, ::core::convert::From::from(e)))
+        };
+        self.rhs = quote!(#rhs.map_err(|e| #error)?);
+    }
+
+    fn replace_rhs(&mut self, rhs: TokenStream) -> TokenStream {
+        std::mem::replace(&mut self.rhs, rhs)
+    }
+
+    /// Path of the assigned field, e.g. 'a.b' for 'other.a.b = rhs;'.
+    fn target_path(&self) -> String {
+        match &self.target {
+            LineTarget::Assign(_, path) => path.to_string().replace(' ', "").replace("r#", ""),
+            _ => String::new(),
+        }
+    }
+
+    /// Turns 'other.a.b = rhs;' into 'rhs.into_existing(&mut other.a.b);'
+    fn nested(self, by_ref: bool, fallible: bool) -> StructLine {
+        let (lhs, rhs) = self.target_and_value(by_ref);
+        let rhs = if fallible { quote!(#rhs.try_into_existing(&mut #lhs)?) } else { quote!(#rhs.into_existing(&mut #lhs)) };
+        StructLine::stmt(rhs)
+    }
+
+    /// Turns 'other.a.b = rhs;' into a check that records 'a.b' if anything inside of it would change.
+    fn nested_diff(self, by_ref: bool) -> TokenStream {
+        let path = self.target_path();
+        let (lhs, rhs) = self.target_and_value(by_ref);
+        quote!(if !#rhs.diff_into(&#lhs).is_empty() { changes.push(o2o::traits::FieldChange::new(#path)); })
+    }
+
+    fn target_and_value(&self, by_ref: bool) -> (TokenStream, TokenStream) {
+        let lhs = match &self.target {
+            LineTarget::Assign(root, path) => quote!(#root.#path),
+            _ => TokenStream::new(),
+        };
+        let rhs = &self.rhs;
+        let rhs = if by_ref { quote!((&(#rhs))) } else { quote!((#rhs)) };
+        (lhs, rhs)
+    }
+
+    /// Turns 'other.a.b = rhs;' into a check that records 'a.b' if the value would change.
+    fn diff(self) -> TokenStream {
+        let path = self.target_path();
+        let (lhs, rhs) = self.target_and_value(false);
+        quote!(if ::core::cmp::PartialEq::ne(&#lhs, &#rhs) { changes.push(o2o::traits::FieldChange::new(#path)); })
+    }
+}
+
+impl ToTokens for StructLine {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let rhs = &self.rhs;
+        tokens.extend(match &self.target {
+            LineTarget::Field(name) => quote!(#name: #rhs,),
+            LineTarget::Positional => quote!(#rhs,),
+            LineTarget::Assign(root, path) => quote!(#root.#path = #rhs;),
+            LineTarget::Stmt => quote!(#rhs;),
+        });
+    }
+}
+
+fn render_struct_line_inner(
+    f: &Field,
+    ctx: &ImplContext,
+    hint: TypeHint,
+    idx: usize,
+    parent_child: Option<&ParentChildField>
+) -> Option<StructLine>
 {
     let member = parent_child.map(|p| &p.this_member)
         .unwrap_or(&f.member);
@@ -666,151 +1176,249 @@ fn render_struct_line(
         },
         None => x.to_token_stream()
     };
+    let map_opt = parent_child.is_none().then(|| f.attrs.map_opt(&ctx.struct_attr.ty)).flatten();
+    let map_items = parent_child.is_none().then(|| f.attrs.map_items(&ctx.struct_attr.ty)).flatten();
+    let rhs = |x: TokenStream| match (map_opt, map_items) {
+        (Some(_), _) => quote_map_opt(x, ctx),
+        (None, Some(map_items)) => quote_map_items(x, f, map_items, ctx),
+        (None, None) if ctx.struct_attr.convert_fields && ctx.impl_type.is_variant() => quote_convert_field(x, ctx),
+        (None, None) => x
+    };
 
     let obj = if ctx.impl_type.is_variant() { TokenStream::new() } else {
-        match ctx.kind {
-            Kind::OwnedInto => quote!(self.),
-            Kind::RefInto => quote!(self.),
-            Kind::FromOwned => quote!(value.),
-            Kind::FromRef => quote!(value.),
-            Kind::OwnedIntoExisting => quote!(self.),
-            Kind::RefIntoExisting => quote!(self.),
-        }
+        let this = ctx.this();
+        quote!(#this.)
     };
 
-    match (member, attr, &ctx.kind, hint) {
-        (Named(ident), None, Kind::OwnedInto | Kind::RefInto, TypeHint::Struct | TypeHint::Unspecified) =>
-            if ctx.has_post_init { quote!(obj.#ident = #obj #ident;) } else { quote!(#ident: #obj #ident,) },
+    let line = match (member, attr, &ctx.kind, hint) {
+        (Named(ident), None, Kind::OwnedInto | Kind::RefInto, TypeHint::Struct | TypeHint::Unspecified) => {
+            let field_name = ctx.rename(&f.member);
+            let right_side = rhs(quote!(#obj #ident));
+            if ctx.has_post_init {
+                let field_path = get_field_path(&field_name);
+                StructLine::assign(quote!(obj), field_path, right_side)
+            } else {
+                StructLine::field(field_name, right_side)
+            }
+        },
         (Named(ident), None, Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Struct | TypeHint::Unspecified) => {
-            let field_path = get_field_path(&f.member);
-            quote!(other.#field_path = #obj #ident;)
+            let field_path = get_field_path(&ctx.rename(&f.member));
+            let right_side = rhs(quote!(#obj #ident));
+            StructLine::assign(quote!(other), field_path, right_side)
+        },
+        (Named(ident), None, Kind::OwnedInto | Kind::RefInto, TypeHint::Tuple) => {
+            let right_side = rhs(quote!(#obj #ident));
+            StructLine::positional(right_side)
         },
-        (Named(ident), None, Kind::OwnedInto | Kind::RefInto, TypeHint::Tuple) => 
-            quote!(#obj #ident,),
         (Named(ident), None, Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Tuple) => {
             let index = Unnamed(Index { index: f.idx as u32, span: Span::call_site() });
-            quote!(other.#index = #obj #ident;)
+            let right_side = rhs(quote!(#obj #ident));
+            StructLine::assign(quote!(other), index, right_side)
         },
         (Named(ident), None, Kind::FromOwned | Kind::FromRef, TypeHint::Struct | TypeHint::Unspecified | TypeHint::Unit) =>
             if f.attrs.has_parent_attr(&ctx.struct_attr.ty) {
                 match (ctx.kind.is_ref(), ctx.fallible) {
-                    (true, true) => quote!(#ident: value.try_into()?,),
-                    (true, false) => quote!(#ident: value.into(),),
-                    (false, true) => quote!(#ident: (&value).try_into()?,),
-                    (false, false) => quote!(#ident: (&value).into(),),
+                    (true, true) => StructLine::field(ident, quote!(value.try_into()?)),
+                    (true, false) => StructLine::field(ident, quote!(value.into())),
+                    (false, true) => StructLine::field(ident, quote!((&value).try_into()?)),
+                    (false, false) => StructLine::field(ident, quote!((&value).into())),
                 }
             } else {
-                let field_path = get_field_path(&f.member);
-                quote!(#ident: #obj #field_path,)
+                let field_path = get_field_path(&ctx.rename(&f.member));
+                let right_side = rhs(quote!(#obj #field_path));
+                StructLine::field(ident, right_side)
             },
         (Named(ident), None, Kind::FromOwned | Kind::FromRef, TypeHint::Tuple) => {
             let index = Unnamed(Index { index: f.idx as u32, span: Span::call_site() });
             let field_path = if ctx.impl_type.is_variant() { get_field_path(&Named(format_ident!("f{}", index))) } else { get_field_path(&index) };
-            quote!(#ident: #obj #field_path,)
+            let right_side = rhs(quote!(#obj #field_path));
+            StructLine::field(ident, right_side)
         },
         (Unnamed(index), None, Kind::OwnedInto | Kind::RefInto, TypeHint::Tuple | TypeHint::Unspecified) =>
             if ctx.has_post_init {
                 let index2 = Unnamed(Index { index: idx as u32, span: Span::call_site() });
-                quote!(obj.#index2 = #obj #index;)
+                let left_field_path = get_field_path(&index2);
+                let right_side = rhs(quote!(#obj #index));
+                StructLine::assign(quote!(obj), left_field_path, right_side)
             } else {
                 let index = if ctx.impl_type.is_variant() { format_ident!("f{}", index.index).to_token_stream() } else { index.to_token_stream() };
-                quote!(#obj #index,)
+                let right_side = rhs(quote!(#obj #index));
+                StructLine::positional(right_side)
             },
         (Unnamed(index), None, Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Tuple | TypeHint::Unspecified) => {
             let index2 = Unnamed(Index { index: f.idx as u32, span: Span::call_site() });
-            quote!(other.#index2 = #obj #index;)
+            let right_side = rhs(quote!(#obj #index));
+            StructLine::assign(quote!(other), index2, right_side)
         },
         (Unnamed(index), None, Kind::FromOwned | Kind::FromRef, TypeHint::Tuple | TypeHint::Unspecified | TypeHint::Unit) =>
             if f.attrs.has_parent_attr(&ctx.struct_attr.ty) {
                 match (ctx.kind.is_ref(), ctx.fallible) {
-                    (true, true) => quote!(value.try_into()?,),
-                    (true, false) => quote!(value.into(),),
-                    (false, true) => quote!((&value).try_into()?,),
-                    (false, false) => quote!((&value).into(),),
+                    (true, true) => StructLine::positional(quote!(value.try_into()?)),
+                    (true, false) => StructLine::positional(quote!(value.into())),
+                    (false, true) => StructLine::positional(quote!((&value).try_into()?)),
+                    (false, false) => StructLine::positional(quote!((&value).into())),
                 }
             } else {
                 let field_path = if ctx.impl_type.is_variant() { get_field_path(&Named(format_ident!("f{}", index.index))) } else { get_field_path(&f.member) };
-                quote!(#obj #field_path,)
+                let right_side = rhs(quote!(#obj #field_path));
+                StructLine::positional(right_side)
             },
         (Unnamed(_), None, _, TypeHint::Struct) =>
             if f.attrs.has_parent_attr(&ctx.struct_attr.ty) {
                 match (ctx.kind.is_ref(), ctx.fallible) {
-                    (true, true) => quote!(value.try_into()?,),
-                    (true, false) => quote!(value.into(),),
-                    (false, true) => quote!((&value).try_into()?,),
-                    (false, false) => quote!((&value).into(),),
+                    (true, true) => StructLine::positional(quote!(value.try_into()?)),
+                    (true, false) => StructLine::positional(quote!(value.into())),
+                    (false, true) => StructLine::positional(quote!((&value).try_into()?)),
+                    (false, false) => StructLine::positional(quote!((&value).into())),
                 }
             } else {
                 unreachable!("6")
             },
         (Named(_), Some(attr), Kind::OwnedInto | Kind::RefInto, TypeHint::Struct | TypeHint::Unspecified) => {
-            let field_name = attr.get_field_name_or(&f.member);
+            let other = ctx.rename(&f.member);
+            let field_name = attr.get_field_name_or(&other);
             let field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&field_path), ctx, || quote!(#obj #field_path));
-            if ctx.has_post_init { quote!(obj.#field_name = #right_side;) } else { quote!(#field_name: #right_side,) }
+            let right_side = attr.get_action_or(Some(&field_path), ctx, || rhs(quote!(#obj #field_path)));
+            if ctx.has_post_init {
+                let left_field_path = get_field_path(field_name);
+                StructLine::assign(quote!(obj), left_field_path, right_side)
+            } else {
+                StructLine::field(field_name, right_side)
+            }
         },
         (Named(_), Some(attr), Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Struct | TypeHint::Unspecified) => {
-            let left_field_path = get_field_path(attr.get_field_name_or(&f.member));
+            let other = ctx.rename(&f.member);
+            let left_field_path = get_field_path(attr.get_field_name_or(&other));
             let right_field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || quote!(#obj #right_field_path));
-            quote!(other.#left_field_path = #right_side;)
+            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || rhs(quote!(#obj #right_field_path)));
+            StructLine::assign(quote!(other), left_field_path, right_side)
         },
         (Named(_), Some(attr), Kind::OwnedInto | Kind::RefInto, TypeHint::Tuple) => {
             let right_field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || quote!(#obj #right_field_path));
-            quote!(#right_side,)
+            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || rhs(quote!(#obj #right_field_path)));
+            StructLine::positional(right_side)
         },
         (Named(_), Some(attr), Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Tuple) => {
             let left_field_path = get_field_path(&Unnamed(Index { index: idx as u32, span: Span::call_site() }));
             let right_field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || quote!(#obj #right_field_path));
-            quote!(other.#left_field_path = #right_side;)
+            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || rhs(quote!(#obj #right_field_path)));
+            StructLine::assign(quote!(other), left_field_path, right_side)
         },
         (Named(_), Some(attr), Kind::FromOwned | Kind::FromRef, TypeHint::Struct | TypeHint::Unspecified | TypeHint::Unit) => {
-            let right_side = attr.get_stuff(&obj, get_field_path, ctx, || &f.member);
+            let other = ctx.rename(&f.member);
+            let right_side = attr.get_stuff(&obj, get_field_path, ctx, || &other);
+            let right_side = if attr.has_action() { right_side } else { rhs(right_side) };
             let idnt = parent_child.map_or(&f.member, |g| &g.this_member);
-            quote!(#idnt: #right_side,)
+            StructLine::field(idnt, right_side)
         },
         (Named(ident), Some(attr), Kind::FromOwned | Kind::FromRef, TypeHint::Tuple) => {
             let or = Named(format_ident!("f{}", f.idx));
             let right_side = attr.get_stuff(&obj, get_field_path, ctx, || if ctx.impl_type.is_variant() { &or } else { &f.member });
-            quote!(#ident: #right_side,)
+            let right_side = if attr.has_action() { right_side } else { rhs(right_side) };
+            StructLine::field(ident, right_side)
         },
         (Unnamed(index), Some(attr), Kind::OwnedInto | Kind::RefInto, TypeHint::Tuple | TypeHint::Unspecified) => {
             let index = if ctx.impl_type.is_variant() { &Member::Named(format_ident!("f{}", index.index)) } else { &f.member };
             let field_path = get_child_field_path(index);
-            let right_side = attr.get_action_or(Some(&field_path), ctx, || quote!(#obj #field_path));
-            quote!(#right_side,)
+            let right_side = attr.get_action_or(Some(&field_path), ctx, || rhs(quote!(#obj #field_path)));
+            StructLine::positional(right_side)
         },
         (Unnamed(_), Some(attr), Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Tuple | TypeHint::Unspecified) => {
             let left_field_path = get_field_path(attr.get_field_name_or(&f.member));
             let right_field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || quote!(#obj #right_field_path));
-            quote!(other.#left_field_path = #right_side;)
+            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || rhs(quote!(#obj #right_field_path)));
+            StructLine::assign(quote!(other), left_field_path, right_side)
         },
         (Unnamed(index), Some(attr), Kind::OwnedInto | Kind::RefInto, TypeHint::Struct) => {
             let field_name = attr.get_ident();
             let field_path = get_child_field_path(&f.member);
             let or = if ctx.impl_type.is_variant() { format_ident!("f{}", index.index).to_token_stream() } else { field_path };
-            let right_side = attr.get_action_or(Some(&or), ctx, || quote!(#obj #or));
+            let right_side = attr.get_action_or(Some(&or), ctx, || rhs(quote!(#obj #or)));
             if ctx.has_post_init {
-                quote!(obj.#field_name = #right_side;)
+                let left_field_path = get_field_path(field_name);
+                StructLine::assign(quote!(obj), left_field_path, right_side)
             } else {
-                quote!(#field_name: #right_side,)
+                StructLine::field(field_name, right_side)
             }
         },
         (Unnamed(_), Some(attr), Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Struct) => {
             let left_field_path = get_field_path(attr.get_ident());
             let right_field_path = get_child_field_path(&f.member);
-            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || quote!(#obj #right_field_path));
-            quote!(other.#left_field_path = #right_side;)
+            let right_side = attr.get_action_or(Some(&right_field_path), ctx, || rhs(quote!(#obj #right_field_path)));
+            StructLine::assign(quote!(other), left_field_path, right_side)
         },
         (Unnamed(index), Some(attr), Kind::FromOwned | Kind::FromRef, _) => {
             let or = Named(format_ident!("f{}", index.index));
             let right_side = attr.get_stuff(&obj, get_field_path, ctx, || if ctx.impl_type.is_variant() { &or } else { &f.member });
-            quote!(#right_side,)
+            let right_side = if attr.has_action() { right_side } else { rhs(right_side) };
+            StructLine::positional(right_side)
         },
-        (_, _, Kind::OwnedInto | Kind::RefInto | Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Unit) => TokenStream::new(),
+        (_, _, Kind::OwnedInto | Kind::RefInto | Kind::OwnedIntoExisting | Kind::RefIntoExisting, TypeHint::Unit) => return None,
+    };
+    Some(line)
+}
+
+/// Like 'quote_map_opt' and 'quote_map_items', converts with 'Into' in infallible instructions and with 'TryInto' and '?' in fallible ones.
+/// Members, that should be converted differently (e.g. with 'Into' in a fallible instruction), need an explicit action.
+fn quote_convert_field(value: TokenStream, ctx: &ImplContext) -> TokenStream {
+    match ctx.fallible {
+        false => quote!(::core::convert::Into::into(#value)),
+        true => quote!(::core::convert::TryInto::try_into(#value)?),
+    }
+}
+
+fn quote_map_opt(value: TokenStream, ctx: &ImplContext) -> TokenStream {
+    match (ctx.kind.is_ref(), ctx.fallible) {
+        (false, false) => quote!(#value.map(::core::convert::Into::into)),
+        (true, false) => quote!(#value.as_ref().map(::core::convert::Into::into)),
+        (false, true) => quote!(#value.map(::core::convert::TryInto::try_into).transpose()?),
+        (true, true) => quote!(#value.as_ref().map(::core::convert::TryInto::try_into).transpose()?),
+    }
+}
+
+fn quote_map_items(value: TokenStream, f: &Field, attr: &MapItemsAttr, ctx: &ImplContext) -> TokenStream {
+    let (keys, values) = (attr.keys, attr.values);
+
+    if f.is_array && !keys && !values {
+        return match (ctx.kind.is_ref(), ctx.fallible) {
+            (false, false) => quote!(#value.map(|x| x.into())),
+            (true, false) => quote!(::core::array::from_fn(|i| (&#value[i]).into())),
+            (is_ref, true) => {
+                let convert = quote!(match x.try_into() {
+                    ::core::result::Result::Ok(x) => ::core::option::Option::Some(x),
+                    ::core::result::Result::Err(e) => { err.get_or_insert(e); ::core::option::Option::None }
+                });
+                let items = if is_ref { quote!(::core::array::from_fn(|i| { let x = &#value[i]; #convert })) } else { quote!(#value.map(|x| #convert)) };
+                quote!({
+                    let mut err = ::core::option::Option::None;
+                    let items = #items;
+                    match err {
+                        ::core::option::Option::Some(e) => ::core::result::Result::Err(e),
+                        ::core::option::Option::None => ::core::result::Result::Ok(items.map(|x| x.unwrap())),
+                    }
+                }?)
+            }
+        };
+    }
+
+    let iter = if ctx.kind.is_ref() { quote!(#value.iter()) } else { quote!(#value.into_iter()) };
+    let convert = |x: TokenStream| if ctx.fallible { quote!(#x.try_into()?) } else { quote!(#x.into()) };
+    let keep = |x: TokenStream| if ctx.kind.is_ref() { quote!(#x.clone()) } else { x };
+
+    let (args, item) = if keys || values {
+        let k = if keys { convert(quote!(k)) } else { keep(quote!(k)) };
+        let v = if values { convert(quote!(v)) } else { keep(quote!(v)) };
+        (quote!((k, v)), quote!((#k, #v)))
+    } else {
+        (quote!(x), convert(quote!(x)))
+    };
+
+    if ctx.fallible {
+        let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+        let item = if keys || values { quote!(::core::result::Result::<_, #err_ty>::Ok(#item)) } else { quote!(x.try_into().map_err(::core::convert::Into::<#err_ty>::into)) };
+        quote!(#iter.map(|#args| #item).collect::<::core::result::Result<_, #err_ty>>()?)
+    } else {
+        quote!(#iter.map(|#args| #item).collect())
     }
 }
 
@@ -818,6 +1426,10 @@ fn render_enum_line(v: &Variant, ctx: &ImplContext) -> TokenStream {
     let attr = v.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty);
     let lit = v.attrs.lit(&ctx.struct_attr.ty);
     let pat = v.attrs.pat(&ctx.struct_attr.ty);
+
+    if let (Some(tag), None) = (&ctx.struct_attr.tag, &attr) {
+        return render_tagged_enum_line(v, tag, lit, pat, ctx);
+    }
     let var = v.attrs.type_hint(&ctx.struct_attr.ty);
 
     let src = ctx.src_ty;
@@ -827,6 +1439,7 @@ fn render_enum_line(v: &Variant, ctx: &ImplContext) -> TokenStream {
 
     let variant_struct: Struct<'_> = Struct {
         attrs: DataTypeAttrs { ghosts_attrs: v.attrs.ghosts_attrs.clone(), ..Default::default() },
+        vis: &Visibility::Inherited,
         ident,
         generics: &Default::default(),
         fields: v.fields.clone(),
@@ -898,6 +1511,11 @@ fn render_enum_line(v: &Variant, ctx: &ImplContext) -> TokenStream {
 }
 
 fn render_ghost_line(ghost_data: &GhostData, ctx: &ImplContext) -> TokenStream {
+    let line = ghost_struct_line(ghost_data, ctx);
+    if ctx.diff { line.diff() } else { line.into_token_stream() }
+}
+
+fn ghost_struct_line(ghost_data: &GhostData, ctx: &ImplContext) -> StructLine {
     let ch = match &ghost_data.child_path {
         Some(ghost_data) => {
             let ch = ghost_data.child_path.to_token_stream();
@@ -908,14 +1526,99 @@ fn render_ghost_line(ghost_data: &GhostData, ctx: &ImplContext) -> TokenStream {
     let right_side = quote_action(&ghost_data.action, None, ctx);
     let ghost_ident = &ghost_data.ghost_ident.get_ident();
     match (ghost_ident, &ctx.kind) {
-        (Named(ident), Kind::OwnedInto | Kind::RefInto) => quote!(#ident: #right_side,),
-        (Unnamed(_), Kind::OwnedInto | Kind::RefInto) => quote!(#right_side,),
-        (Named(ident), Kind::OwnedIntoExisting | Kind::RefIntoExisting) => quote!(other.#ch #ident = #right_side;),
-        (Unnamed(index), Kind::OwnedIntoExisting | Kind::RefIntoExisting) => quote!(other.#ch #index = #right_side;),
+        (Named(ident), Kind::OwnedInto | Kind::RefInto) if ctx.has_post_init => StructLine::assign(quote!(obj), quote!(#ch #ident), right_side),
+        (Unnamed(index), Kind::OwnedInto | Kind::RefInto) if ctx.has_post_init => StructLine::assign(quote!(obj), quote!(#ch #index), right_side),
+        (Named(ident), Kind::OwnedInto | Kind::RefInto) => StructLine::field(ident, right_side),
+        (Unnamed(_), Kind::OwnedInto | Kind::RefInto) => StructLine::positional(right_side),
+        (Named(ident), Kind::OwnedIntoExisting | Kind::RefIntoExisting) => StructLine::assign(quote!(other), quote!(#ch #ident), right_side),
+        (Unnamed(index), Kind::OwnedIntoExisting | Kind::RefIntoExisting) => StructLine::assign(quote!(other), quote!(#ch #index), right_side),
         (_, _) => unreachable!("7"),
     }
 }
 
+fn tagged_match_expr(input: &Enum, tag: &Member, ctx: &ImplContext) -> TokenStream {
+    let str_tag = input.variants.iter().any(|v| v.attrs.lit(&ctx.struct_attr.ty).is_some_and(is_str_lit));
+    if str_tag { quote!(&*value.#tag) } else { quote!(value.#tag) }
+}
+
+fn is_str_lit(lit: &LitAttr) -> bool {
+    syn::parse2::<syn::LitStr>(lit.tokens.clone()).is_ok()
+}
+
+fn tagged_field_name(f: &Field, ctx: &ImplContext) -> Member {
+    let other = ctx.rename(&f.member);
+    let attr = f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty);
+    attr.map_or(other.clone(), |attr| attr.get_field_name_or(&other).clone())
+}
+
+fn render_tagged_enum_line(v: &Variant, tag: &Member, lit: Option<&LitAttr>, pat: Option<&PatAttr>, ctx: &ImplContext) -> TokenStream {
+    let src = ctx.src_ty;
+    let dst = ctx.dst_ty;
+    let ident = &v.ident;
+
+    if ctx.kind.is_from() {
+        let new_ctx = ImplContext { impl_type: ImplType::Struct, ..*ctx };
+        let left_side = lit.map(|x| &x.tokens).or(pat.map(|x| &x.tokens));
+
+        // Tagged conversions from structs are always fallible (see validate_tag),
+        // so missing fields are reported instead of unwrapped
+        let unwrap = |other: &Member| {
+            let name = shape_field_name(other);
+            quote!(value.#other.ok_or(o2o::traits::MissingFieldError(#name))?)
+        };
+        let inits = v.fields.iter().map(|f| {
+            let member = &f.member;
+            let right_side = match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty) {
+                Some(ApplicableAttr::Ghost(ghost)) => ghost.action.as_ref()
+                    .map_or(quote!(Default::default()), |x| quote_action(x, None, &new_ctx)),
+                Some(attr) => {
+                    let other = tagged_field_name(f, ctx);
+                    attr.get_action_or(Some(&other.to_token_stream()), &new_ctx, || unwrap(&other))
+                },
+                None => unwrap(&ctx.rename(&f.member)),
+            };
+            quote!(#member: #right_side,)
+        });
+
+        let init = if v.unit { TokenStream::new() } else { quote!({#(#inits)*}) };
+        quote!(#left_side => #dst::#ident #init,)
+    } else {
+        let new_ctx = ImplContext { impl_type: ImplType::Variant, ..*ctx };
+        let lit = lit.unwrap();
+        let tag_value = &lit.tokens;
+        let tag_value = if is_str_lit(lit) { quote!(#tag_value.into()) } else { quote!(#tag_value) };
+
+        let fields: Vec<&Field> = v.fields.iter().filter(|f| f.attrs.ghost(&ctx.struct_attr.ty, &ctx.kind).is_none()).collect();
+        let values: Vec<(Member, TokenStream)> = fields.iter().map(|f| {
+            let member = &f.member;
+            let right_side = match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, &ctx.struct_attr.ty) {
+                Some(attr) => attr.get_action_or(Some(&member.to_token_stream()), &new_ctx, || member.to_token_stream()),
+                None => member.to_token_stream(),
+            };
+            (tagged_field_name(f, ctx), right_side)
+        }).collect();
+
+        let mut names: Vec<Member> = vec![];
+        if let DataType::Enum(e) = ctx.input {
+            for f in e.variants.iter().flat_map(|x| &x.fields).filter(|f| f.attrs.ghost(&ctx.struct_attr.ty, &ctx.kind).is_none()) {
+                let name = tagged_field_name(f, ctx);
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        let inits = names.iter().map(|name| match values.iter().find(|(x, _)| x == name) {
+            Some((_, right_side)) => quote!(#name: ::core::option::Option::Some(#right_side),),
+            None => quote!(#name: ::core::option::Option::None,),
+        });
+
+        let bindings = fields.iter().map(|f| &f.member);
+        let destr = if v.unit { TokenStream::new() } else { quote!({ #(#bindings,)* .. }) };
+        quote!(#src::#ident #destr => #dst { #tag: #tag_value, #(#inits)* },)
+    }
+}
+
 fn render_enum_ghost_line(ghost_data: &GhostData, ctx: &ImplContext) -> TokenStream {
     let src = ctx.src_ty;
     let right_side = quote_action(&ghost_data.action, None, ctx);
@@ -972,10 +1675,7 @@ fn replace_tilde_or_at_in_expr(input: &TokenStream, at_tokens: Option<&TokenStre
 
 fn quote_action(action: &TokenStream, tilde_postfix: Option<&TokenStream>, ctx: &ImplContext) -> TokenStream {
     let dst = ctx.dst_ty;
-    let ident = match ctx.kind {
-        Kind::FromOwned | Kind::FromRef => quote!(value),
-        _ => quote!(self),
-    };
+    let ident = ctx.this();
     let path = match ctx.impl_type {
         ImplType::Struct => quote!(#ident.#tilde_postfix),
         ImplType::Enum => quote!(#dst::#tilde_postfix),
@@ -984,6 +1684,101 @@ fn quote_action(action: &TokenStream, tilde_postfix: Option<&TokenStream>, ctx:
     replace_tilde_or_at_in_expr(action, Some(&ident), Some(&path))
 }
 
+/// Pairs type parameters of the data type with generic arguments of the instruction type (e.g. 'U' in 'PageDto<U>' with 'T' in 'Page<T>'),
+/// and produces 'Into'/'TryInto' bounds for those, that are mentioned by types of converted members.
+/// Generic arguments listed in 'infer_bounds(...)' are added to the generic parameters of the implementation.
+fn infer_bounds(input: &DataType, ctx: &ImplContext, params: &[Ident], impl_gens: &mut Generics) -> Vec<TokenStream> {
+    let these: Vec<&Ident> = input.get_generics().type_params().map(|x| &x.ident).collect();
+    let those: Vec<&Type> = ctx.struct_attr.ty.generics.as_ref().map(|g| g.args.iter().filter_map(|g| match g {
+        GenericArgument::Type(t) => Some(t),
+        _ => None
+    }).collect()).unwrap_or_default();
+
+    let fields: Vec<&Field> = match input {
+        DataType::Struct(s) => s.fields.iter().collect(),
+        DataType::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+    };
+    let converted: Vec<(&Field, bool)> = fields.into_iter().filter_map(|f| conversion(f, ctx).map(|x| (f, x))).collect();
+
+    let mut bounds = vec![];
+    for (this, that) in these.iter().zip(those) {
+        let fallible: Vec<bool> = converted.iter()
+            .filter(|(f, _)| f.ty.as_ref().is_some_and(|ty| mentions_ident(ty.to_token_stream(), &this.to_string())))
+            .map(|(_, fallible)| *fallible)
+            .collect();
+        if that.to_token_stream().to_string() == this.to_string() || fallible.is_empty() {
+            continue;
+        }
+
+        if let Type::Path(p) = that {
+            if let Some(ident) = p.path.get_ident() {
+                if params.contains(ident) && !impl_gens.type_params().any(|x| &x.ident == ident) {
+                    impl_gens.params.push(parse_quote!(#ident));
+                }
+            }
+        }
+
+        let (src, dst) = if ctx.kind.is_from() { (quote!(#that), quote!(#this)) } else { (quote!(#this), quote!(#that)) };
+        let (hrtb, src) = if ctx.kind.is_ref() { (quote!(for<'o2o_b>), quote!(&'o2o_b #src)) } else { (TokenStream::new(), src) };
+
+        if fallible.contains(&true) {
+            bounds.push(quote!(#hrtb #src: ::core::convert::TryInto<#dst>));
+            if ctx.struct_attr.map_err.is_none() {
+                let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+                bounds.push(quote!(#hrtb #err_ty: ::core::convert::From<<#src as ::core::convert::TryInto<#dst>>::Error>));
+            }
+        }
+        if fallible.contains(&false) {
+            bounds.push(quote!(#hrtb #src: ::core::convert::Into<#dst>));
+        }
+    }
+    bounds
+}
+
+/// Whether the member is converted, and if so, whether the conversion is fallible.
+fn conversion(f: &Field, ctx: &ImplContext) -> Option<bool> {
+    let ty = &ctx.struct_attr.ty;
+    if f.attrs.map_opt(ty).is_some() || f.attrs.map_items(ty).is_some() {
+        return Some(ctx.fallible);
+    }
+    match f.attrs.applicable_attr(&ctx.kind, ctx.fallible, ty) {
+        Some(ApplicableAttr::Field(MemberAttrCore { action: Some(action), .. })) => ["into", "try_into", "Into", "TryInto"].iter().any(|x| mentions_ident(action.clone(), x)).then_some(ctx.fallible),
+        Some(ApplicableAttr::Field(MemberAttrCore { action: None, .. })) | None => (ctx.struct_attr.convert_fields && matches!(ctx.input, DataType::Enum(_))).then_some(ctx.fallible),
+        _ => None,
+    }
+}
+
+/// Compares types the way they are written, except that paths match if one is a suffix of the other, e.g. 'String' and 'std::string::String'.
+fn same_type(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Path(a), Type::Path(b)) if a.qself.is_none() && b.qself.is_none() => {
+            let len = a.path.segments.len().min(b.path.segments.len());
+            a.path.segments.iter().rev().zip(b.path.segments.iter().rev()).take(len).all(|(a, b)| a.ident == b.ident && match (&a.arguments, &b.arguments) {
+                (PathArguments::AngleBracketed(a), PathArguments::AngleBracketed(b)) => a.args.len() == b.args.len() && a.args.iter().zip(&b.args).all(|x| match x {
+                    (GenericArgument::Type(a), GenericArgument::Type(b)) => same_type(a, b),
+                    (a, b) => a.to_token_stream().to_string() == b.to_token_stream().to_string(),
+                }),
+                (a, b) => a.to_token_stream().to_string() == b.to_token_stream().to_string(),
+            })
+        },
+        (Type::Reference(a), Type::Reference(b)) => a.mutability.is_some() == b.mutability.is_some() && same_type(&a.elem, &b.elem),
+        (Type::Tuple(a), Type::Tuple(b)) => a.elems.len() == b.elems.len() && a.elems.iter().zip(&b.elems).all(|(a, b)| same_type(a, b)),
+        (Type::Array(a), Type::Array(b)) => a.len.to_token_stream().to_string() == b.len.to_token_stream().to_string() && same_type(&a.elem, &b.elem),
+        (Type::Slice(a), Type::Slice(b)) => same_type(&a.elem, &b.elem),
+        (Type::Paren(a), b) => same_type(&a.elem, b),
+        (a, Type::Paren(b)) => same_type(a, &b.elem),
+        (a, b) => a.to_token_stream().to_string() == b.to_token_stream().to_string(),
+    }
+}
+
+fn mentions_ident(tokens: TokenStream, ident: &str) -> bool {
+    tokens.into_iter().any(|x| match x {
+        TokenTree::Ident(x) => x == ident,
+        TokenTree::Group(g) => mentions_ident(g.stream(), ident),
+        _ => false,
+    })
+}
+
 struct QuoteTraitParams<'a> {
     pub attr: Option<&'a TokenStream>,
     pub impl_attr: Option<&'a TokenStream>,
@@ -1031,6 +1826,20 @@ fn get_quote_trait_params<'a>(input: &DataType, ctx: &'a ImplContext) -> QuoteTr
         impl_gens.params.push(parse_quote!('o2o: #( #ref_lts )+*));
     }
 
+    let where_attr = input.get_attrs().where_attr(&ctx.struct_attr.ty);
+    let inferred_bounds = match &ctx.struct_attr.infer_bounds {
+        Some(params) => infer_bounds(input, ctx, params, &mut impl_gens),
+        None => vec![],
+    };
+    let where_clause = match where_attr {
+        Some(x) => {
+            let where_clause = &x.where_clause;
+            Some(quote!(where #where_clause))
+        },
+        None if !inferred_bounds.is_empty() => Some(quote!(where #(#inferred_bounds),*)),
+        None => None,
+    };
+
     QuoteTraitParams { 
         attr: ctx.struct_attr.attribute.as_ref(), 
         impl_attr: ctx.struct_attr.impl_attribute.as_ref(), 
@@ -1040,10 +1849,7 @@ fn get_quote_trait_params<'a>(input: &DataType, ctx: &'a ImplContext) -> QuoteTr
         these_gens: input.get_generics().to_token_stream(),
         those_gens: ctx.struct_attr.ty.generics.to_token_stream(),
         impl_gens: impl_gens.to_token_stream(), 
-        where_clause: input.get_attrs().where_attr(&ctx.struct_attr.ty).map(|x| {
-            let where_clause = &x.where_clause;
-            quote!(where #where_clause)
-        }), 
+        where_clause, 
         r: ctx.kind.is_ref().then_some(if ref_lts.is_empty() { quote!(&) } else { quote!(&'o2o) }) 
     }
 }
@@ -1055,13 +1861,325 @@ fn quote_trait(input: &DataType, ctx: &mut ImplContext) -> TokenStream {
     };
     ctx.has_post_init = post_init.is_some();
 
-    match (ctx.kind, ctx.fallible) {
+    let shape_check = match input {
+        DataType::Struct(s) if ctx.struct_attr.check_shape && ctx.struct_attr.quick_return.is_none() => Some(quote_shape_check(s, ctx)),
+        _ => None
+    };
+
+    let imp = match (ctx.kind, ctx.fallible) {
+        _ if !ctx.builder_ghosts().is_empty() => quote_builder(input, ctx, pre_init, post_init),
+        _ if ctx.struct_attr.is_async && ctx.struct_attr.standalone_fn.is_none() => quote_async_trait(input, ctx, pre_init, post_init),
+        _ if ctx.struct_attr.standalone_fn.is_some() => quote_standalone_fn(input, ctx, pre_init, post_init),
+        _ if ctx.struct_attr.with.is_some() => quote_with_trait(input, ctx, pre_init, post_init),
         (Kind::FromOwned, false) | (Kind::FromRef, false) => quote_from_trait(input, ctx, pre_init, main_code_block(ctx)),
         (Kind::FromOwned, true) | (Kind::FromRef, true) => quote_try_from_trait(input, ctx, pre_init, main_code_block_ok(ctx)),
         (Kind::OwnedInto, false) | (Kind::RefInto, false) => quote_into_trait(input, ctx, pre_init, main_code_block(ctx), post_init),
         (Kind::OwnedInto, true) | (Kind::RefInto, true) => quote_try_into_trait(input, ctx, pre_init, main_code_block_ok(ctx), post_init),
         (Kind::OwnedIntoExisting, false) | (Kind::RefIntoExisting, false) => quote_into_existing_trait(input, ctx, pre_init, main_code_block(ctx), post_init),
         (Kind::OwnedIntoExisting, true) | (Kind::RefIntoExisting, true) => quote_try_into_existing_trait(input, ctx, pre_init, main_code_block(ctx), post_init),
+    };
+
+    let diff = (ctx.struct_attr.diff && ctx.kind.is_into_existing()).then(|| {
+        ctx.diff = true;
+        let pre_init = struct_pre_init(ctx);
+        let post_init = struct_post_init(input, ctx);
+        let imp = quote_diff_trait(input, ctx, pre_init, main_code_block(ctx), post_init);
+        ctx.diff = false;
+        imp
+    });
+
+    quote!(#shape_check #imp #diff)
+}
+
+fn quote_shape_check(input: &Struct, ctx: &ImplContext) -> TokenStream {
+    let ty = &ctx.struct_attr.ty;
+    let shape = {
+        let path = &ty.path;
+        let gens = &ty.generics;
+        quote!(<#path #gens as o2o::traits::Shape>)
+    };
+
+    let mut names: Vec<(String, Span)> = vec![];
+    let mut push = |member: &Member| {
+        let name = shape_field_name(member);
+        if names.iter().all(|(x, _)| x != &name) {
+            names.push((name, member.span()));
+        }
+    };
+
+    // Parent instructions pull fields from nested structs, so the field count can't be checked then
+    let mut exhaustive = matches!(ctx.kind, Kind::OwnedInto | Kind::RefInto) && ctx.struct_attr.update.is_none();
+
+    for f in &input.fields {
+        if f.attrs.has_parent_attr(ty) {
+            exhaustive = false;
+            continue;
+        }
+        match (f.attrs.child(ty), f.attrs.applicable_attr(&ctx.kind, ctx.fallible, ty)) {
+            (_, Some(ApplicableAttr::Ghost(_))) => (),
+            (Some(child_attr), _) => push(child_attr.child_path.child_path.first().unwrap()),
+            (None, Some(ApplicableAttr::Field(MemberAttrCore { member: Some(member), .. }))) => push(member),
+            (None, Some(ApplicableAttr::Field(MemberAttrCore { action: Some(_), .. }))) if ctx.kind.is_from() => (),
+            _ => push(&ctx.rename(&f.member)),
+        }
+    }
+
+    if !ctx.kind.is_from() {
+        for ghost_data in input.attrs.ghosts_attr(ty, &ctx.kind).iter().flat_map(|x| &x.ghost_data) {
+            match &ghost_data.child_path {
+                Some(child_path) => push(child_path.child_path.first().unwrap()),
+                None => push(ghost_data.ghost_ident.get_ident()),
+            }
+        }
+    }
+
+    let checks = names.iter().map(|(name, span)| {
+        let msg = format!("Field '{}' doesn't exist in {}.", name, ty.path_str);
+        quote_spanned!(*span=> if !o2o::traits::shape_has_field(#shape::FIELDS, #name) { ::core::panic!(#msg) })
+    });
+
+    let count_check = exhaustive.then(|| {
+        let count = names.len();
+        let msg = format!("Not all fields of {} are initialized.", ty.path_str);
+        quote_spanned!(ty.span=> if #shape::FIELDS.len() != #count { ::core::panic!(#msg) })
+    });
+
+    quote! {
+        const _: () = {
+            #(#checks)*
+            #count_check
+        };
+    }
+}
+
+fn shape_field_name(member: &Member) -> String {
+    match member {
+        Named(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+        Unnamed(index) => index.index.to_string(),
+    }
+}
+
+fn quote_standalone_fn(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, post_init: Option<TokenStream>) -> TokenStream {
+    let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
+    let StandaloneFn { vis, ident } = ctx.struct_attr.standalone_fn.as_ref().unwrap();
+    let ty = input.get_ident();
+    let ret = |ty: TokenStream| if ctx.fallible {
+        let err_ty = ctx.error_ty();
+        quote!(-> ::core::result::Result<#ty, #err_ty>)
+    } else {
+        quote!(-> #ty)
+    };
+    let ok = |x: TokenStream| if ctx.fallible { quote!(Ok(#x)) } else { x };
+    let this = match input.get_attrs().remote_attr() {
+        Some(_) => quote!(value: #r #src #these_gens),
+        None => quote!(#r self),
+    };
+    let with = ctx.struct_attr.with.as_ref().map(|WithArg { ident, ty }| quote!(, #ident: #ty));
+    let asyncness = ctx.struct_attr.is_async.then(|| quote!(async));
+
+    let (params, ret, body) = match ctx.kind {
+        Kind::FromOwned | Kind::FromRef => {
+            let init = if ctx.fallible { main_code_block_ok(ctx) } else { main_code_block(ctx) };
+            (quote!(value: #r #src #those_gens #with), ret(quote!(#dst #these_gens)), quote!(#pre_init #init))
+        },
+        Kind::OwnedInto | Kind::RefInto => {
+            let init = if ctx.fallible { main_code_block_ok(ctx) } else { main_code_block(ctx) };
+            let body = match post_init {
+                Some(post_init) => {
+                    let obj = ok(quote!(obj));
+                    quote!(let mut obj: #dst = Default::default(); #init #post_init #obj)
+                },
+                None => quote!(#pre_init #init),
+            };
+            (quote!(#this #with), ret(quote!(#dst #those_gens)), body)
+        },
+        Kind::OwnedIntoExisting | Kind::RefIntoExisting => {
+            let init = main_code_block(ctx);
+            let (ret, end) = if ctx.fallible { (ret(quote!(())), Some(quote!(Ok(())))) } else { (TokenStream::new(), None) };
+            (quote!(#this, other: &mut #dst #those_gens), ret, quote!(#pre_init #init #post_init #end))
+        },
+    };
+
+    quote! {
+        #impl_attr
+        impl #impl_gens #ty #these_gens #where_clause {
+            #attr
+            #vis #asyncness fn #ident(#params) #ret {
+                #inner_attr
+                #body
+            }
+        }
+    }
+}
+
+fn quote_builder(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, post_init: Option<TokenStream>) -> TokenStream {
+    let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, those_gens, .. } = get_quote_trait_params(input, ctx);
+    let DataType::Struct(s) = input else { unreachable!("19") };
+    let vis = s.vis;
+    let fn_vis = ctx.struct_attr.standalone_fn.as_ref().map_or(vis, |x| &x.vis);
+    let fn_ident = ctx.struct_attr.builder_fn_ident(ctx.fallible);
+    // Builder is named after the method, so that unique method names (checked by validation) give unique builders
+    let builder_name = RenameRule::PascalCase.apply(fn_ident.to_string().trim_end_matches("_builder"));
+    let builder = format_ident!("{}{}Builder", s.ident, builder_name);
+
+    let ghosts = ctx.builder_ghosts();
+    let names: Vec<&Member> = ghosts.iter().map(|x| x.ghost_ident.get_ident()).collect();
+    let tys: Vec<&syn::Type> = ghosts.iter().map(|x| x.required_ty.as_ref().unwrap()).collect();
+    let params: Vec<Ident> = (0..ghosts.len()).map(|i| format_ident!("O2oG{}", i)).collect();
+    let unset = params.iter().map(|_| quote!(o2o::traits::Unset));
+    let asyncness = ctx.struct_attr.is_async.then(|| quote!(async));
+    let with = ctx.struct_attr.with.as_ref().map(|WithArg { ident, ty }| quote!(, #ident: #ty));
+
+    let setters = names.iter().zip(&tys).enumerate().map(|(i, (name, ty))| {
+        let other_params = params.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, x)| x);
+        let before = params.iter().enumerate().map(|(j, x)| if j == i { quote!(o2o::traits::Unset) } else { quote!(#x) });
+        let after = params.iter().enumerate().map(|(j, x)| if j == i { quote!(o2o::traits::Set<#ty>) } else { quote!(#x) });
+        let fields = names.iter().enumerate().map(|(j, x)| if j == i { quote!(#x: o2o::traits::Set(#x)) } else { quote!(#x: self.#x) });
+        quote! {
+            impl<#(#other_params),*> #builder<#(#before),*> {
+                #vis fn #name(self, #name: #ty) -> #builder<#(#after),*> {
+                    #builder { value: self.value, #(#fields),* }
+                }
+            }
+        }
+    });
+
+    let (ret, init, obj) = if ctx.fallible {
+        let err_ty = ctx.error_ty();
+        (quote!(::core::result::Result<#dst #those_gens, #err_ty>), main_code_block_ok(ctx), quote!(Ok(obj)))
+    } else {
+        (quote!(#dst #those_gens), main_code_block(ctx), quote!(obj))
+    };
+    let body = match post_init {
+        Some(post_init) => quote!(let mut obj: #dst = Default::default(); #init #post_init #obj),
+        None => quote!(#pre_init #init),
+    };
+
+    quote! {
+        #vis struct #builder<#(#params),*> {
+            value: #src,
+            #(#names: #params,)*
+        }
+
+        #impl_attr
+        impl #src {
+            #fn_vis fn #fn_ident(self) -> #builder<#(#unset),*> {
+                #builder { value: self, #(#names: o2o::traits::Unset),* }
+            }
+        }
+
+        #(#setters)*
+
+        impl #builder<#(o2o::traits::Set<#tys>),*> {
+            #attr
+            #vis #asyncness fn build(self #with) -> #ret {
+                #inner_attr
+                let value = self.value;
+                #(let #names = self.#names.0;)*
+                #body
+            }
+        }
+    }
+}
+
+fn quote_async_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, post_init: Option<TokenStream>) -> TokenStream {
+    let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
+    let err_ty = ctx.error_ty();
+    let init = main_code_block_ok(ctx);
+
+    if ctx.kind.is_from() {
+        quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::AsyncTryFrom<#r #src #those_gens> for #dst #these_gens #where_clause {
+                type Error = #err_ty;
+                #attr
+                fn try_from_async(value: #r #src #those_gens) -> impl ::core::future::Future<Output = ::core::result::Result<#dst #these_gens, #err_ty>> {
+                    #inner_attr
+                    async move {
+                        #pre_init
+                        #init
+                    }
+                }
+            }
+        }
+    } else {
+        let body = match post_init {
+            Some(post_init) => quote!(let mut obj: #dst = Default::default(); #init #post_init Ok(obj)),
+            None => quote!(#pre_init #init),
+        };
+        quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::AsyncTryInto<#dst #those_gens> for #r #src #these_gens #where_clause {
+                type Error = #err_ty;
+                #attr
+                fn try_into_async(self) -> impl ::core::future::Future<Output = ::core::result::Result<#dst #those_gens, #err_ty>> {
+                    #inner_attr
+                    async move {
+                        #body
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn quote_with_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, post_init: Option<TokenStream>) -> TokenStream {
+    let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
+    let WithArg { ident: arg, ty: arg_ty } = ctx.struct_attr.with.as_ref().unwrap();
+    let err_ty = ctx.fallible.then(|| ctx.error_ty());
+    let init = if ctx.fallible { main_code_block_ok(ctx) } else { main_code_block(ctx) };
+
+    let body = match post_init {
+        Some(post_init) => {
+            let obj = if ctx.fallible { quote!(Ok(obj)) } else { quote!(obj) };
+            quote!(let mut obj: #dst = Default::default(); #init #post_init #obj)
+        },
+        None => quote!(#pre_init #init),
+    };
+
+    match (ctx.kind.is_from(), ctx.fallible) {
+        (true, false) => quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::FromWith<#r #src #those_gens, #arg_ty> for #dst #these_gens #where_clause {
+                #attr
+                fn from_with(value: #r #src #those_gens, #arg: #arg_ty) -> #dst #these_gens {
+                    #inner_attr
+                    #body
+                }
+            }
+        },
+        (true, true) => quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::TryFromWith<#r #src #those_gens, #arg_ty> for #dst #these_gens #where_clause {
+                type Error = #err_ty;
+                #attr
+                fn try_from_with(value: #r #src #those_gens, #arg: #arg_ty) -> ::core::result::Result<#dst #these_gens, #err_ty> {
+                    #inner_attr
+                    #body
+                }
+            }
+        },
+        (false, false) => quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::IntoWith<#dst #those_gens, #arg_ty> for #r #src #these_gens #where_clause {
+                #attr
+                fn into_with(self, #arg: #arg_ty) -> #dst #those_gens {
+                    #inner_attr
+                    #body
+                }
+            }
+        },
+        (false, true) => quote! {
+            #impl_attr
+            impl #impl_gens o2o::traits::TryIntoWith<#dst #those_gens, #arg_ty> for #r #src #these_gens #where_clause {
+                type Error = #err_ty;
+                #attr
+                fn try_into_with(self, #arg: #arg_ty) -> ::core::result::Result<#dst #those_gens, #err_ty> {
+                    #inner_attr
+                    #body
+                }
+            }
+        },
     }
 }
 
@@ -1082,7 +2200,7 @@ fn quote_from_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenS
 
 fn quote_try_from_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, init: TokenStream) -> TokenStream {
     let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
-    let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+    let err_ty = ctx.error_ty();
     quote! {
         #impl_attr
         impl #impl_gens ::core::convert::TryFrom<#r #src #those_gens> for #dst #these_gens #where_clause {
@@ -1113,6 +2231,19 @@ fn quote_into_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenS
         },
     };
 
+    if ctx.struct_attr.impl_from {
+        return quote! {
+            #impl_attr
+            impl #impl_gens ::core::convert::From<#r #src #these_gens> for #dst #those_gens #where_clause {
+                #attr
+                fn from(value: #r #src #these_gens) -> #dst #those_gens {
+                    #inner_attr
+                    #body
+                }
+            }
+        };
+    }
+
     quote! {
         #impl_attr
         impl #impl_gens ::core::convert::Into<#dst #those_gens> for #r #src #these_gens #where_clause {
@@ -1127,7 +2258,7 @@ fn quote_into_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenS
 
 fn quote_try_into_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, init: TokenStream, post_init: Option<TokenStream>) -> TokenStream {
     let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
-    let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+    let err_ty = ctx.error_ty();
 
     let body = match post_init {
         Some(post_init) => quote! {
@@ -1142,6 +2273,20 @@ fn quote_try_into_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<To
         },
     };
 
+    if ctx.struct_attr.impl_from {
+        return quote! {
+            #impl_attr
+            impl #impl_gens ::core::convert::TryFrom<#r #src #these_gens> for #dst #those_gens #where_clause {
+                type Error = #err_ty;
+                #attr
+                fn try_from(value: #r #src #these_gens) -> ::core::result::Result<#dst #those_gens, #err_ty> {
+                    #inner_attr
+                    #body
+                }
+            }
+        };
+    }
+
     quote! {
         #impl_attr
         impl #impl_gens ::core::convert::TryInto<#dst #those_gens> for #r #src #these_gens #where_clause {
@@ -1171,9 +2316,28 @@ fn quote_into_existing_trait(input: &DataType, ctx: &ImplContext, pre_init: Opti
     }
 }
 
+/// Lists fields that 'into_existing' would change, without changing them.
+fn quote_diff_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, init: TokenStream, post_init: Option<TokenStream>) -> TokenStream {
+    let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
+    quote! {
+        #impl_attr
+        impl #impl_gens o2o::traits::DiffInto<#dst #those_gens> for #r #src #these_gens #where_clause {
+            #attr
+            fn diff_into(self, other: &#dst #those_gens) -> o2o::traits::FieldChanges {
+                #inner_attr
+                let mut changes = o2o::traits::FieldChanges::new();
+                #pre_init
+                #init
+                #post_init
+                changes
+            }
+        }
+    }
+}
+
 fn quote_try_into_existing_trait(input: &DataType, ctx: &ImplContext, pre_init: Option<TokenStream>, init: TokenStream, post_init: Option<TokenStream>) -> TokenStream {
     let QuoteTraitParams { attr, impl_attr, inner_attr, dst, src, these_gens, those_gens, impl_gens, where_clause, r } = get_quote_trait_params(input, ctx);
-    let err_ty = &ctx.struct_attr.err_ty.as_ref().unwrap().path;
+    let err_ty = ctx.error_ty();
     quote! {
         #impl_attr
         impl #impl_gens o2o::traits::TryIntoExisting<#dst #those_gens> for #r #src #these_gens #where_clause {
@@ -1261,7 +2425,7 @@ impl<'a> ApplicableAttr<'a> {
         }
     }
 
-    fn get_stuff<F1: Fn(&Member) -> TokenStream, F2: Fn() -> &'a Member>(&self, obj: &TokenStream, field_path: F1, ctx: &ImplContext, or: F2) -> TokenStream {
+    fn get_stuff<'b, F1: Fn(&Member) -> TokenStream, F2: Fn() -> &'b Member>(&self, obj: &TokenStream, field_path: F1, ctx: &ImplContext, or: F2) -> TokenStream {
         let get_stuff = |member: &Option<Member>, action: &Option<TokenStream>| {
             match (member, action) {
                 (Some(ident), Some(action)) => if let Unnamed(index) = ident {
@@ -10,3 +10,21 @@ syn::custom_keyword!(Unit);
 syn::custom_keyword!(attribute);
 syn::custom_keyword!(impl_attribute);
 syn::custom_keyword!(inner_attribute);
+syn::custom_keyword!(keys);
+syn::custom_keyword!(values);
+syn::custom_keyword!(check_shape);
+syn::custom_keyword!(rename_all);
+syn::custom_keyword!(collect_errors);
+syn::custom_keyword!(error_paths);
+syn::custom_keyword!(map_err);
+syn::custom_keyword!(with);
+syn::custom_keyword!(diff);
+syn::custom_keyword!(patch);
+syn::custom_keyword!(skip_if);
+syn::custom_keyword!(nested);
+syn::custom_keyword!(impl_from);
+syn::custom_keyword!(tag);
+syn::custom_keyword!(convert_fields);
+syn::custom_keyword!(infer_bounds);
+syn::custom_keyword!(default_init);
+syn::custom_keyword!(case_insensitive);
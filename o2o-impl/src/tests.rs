@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::expand::derive;
+use crate::expand::{derive, expand_convert, expand_mapping};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -657,6 +657,14 @@ fn dedicated_instruction_defined_twice(code_fragment: TokenStream, err_instr: &s
         Var
     }
 }, "type_hint", "EnumDto"; "enum_type_hint_instr")]
+#[test_case(quote! {
+    #[map(EntityDto)]
+    struct Entity {
+        #[o2o(map_opt(EntityDto))]
+        #[o2o(map_opt(EntityDto))]
+        x: Option<i32>
+    }
+}, "map_opt", "EntityDto"; "struct_map_opt_instr")]
 fn dedicated_member_instruction_defined_twice(code_fragment: TokenStream, err_instr: &str, err_ty: &str) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
@@ -815,6 +823,13 @@ fn dedicated_member_instruction_defined_twice(code_fragment: TokenStream, err_in
         test: i32
     }
 }, vec!["EntityDto123"]; "map_as_type_instr")]
+#[test_case(quote! {
+    #[map(EntityDto)]
+    struct Entity123 {
+        #[o2o(map_opt(EntityDto123))]
+        test: Option<i32>
+    }
+}, vec!["EntityDto123"]; "map_map_opt_instr")]
 fn dedicated_field_instruction_mismatch(code_fragment: TokenStream, errs: Vec<&str>) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
@@ -953,6 +968,21 @@ fn trait_instruction_defined_twice(code_fragment: TokenStream, err: &str) {
     ("EntityDto", false),
     ("EntityModel", true) 
 ]; "3")]
+#[test_case(quote! {
+    #[map(EntityDto)]
+    #[map(EntityModel)]
+    struct Entity {
+        #[child(base.base)]
+        base_base_int: i32,
+        #[child(child)]
+        child_int: i32,
+        #[parent(EntityDto)]
+        parent: Parent,
+    }
+}, vec![
+    ("EntityDto", false),
+    ("EntityModel", true) 
+]; "4")]
 fn missing_child_parents_instruction(code_fragment: TokenStream, errs: Vec<(&str, bool)>) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
@@ -960,8 +990,8 @@ fn missing_child_parents_instruction(code_fragment: TokenStream, errs: Vec<(&str
 
     for (ty, should_contain) in errs {
         match should_contain {
-            true => assert!(errors.iter().any(|x| x.to_string() == format!("Missing #[child_parents(...)] instruction for {}", ty))),
-            false => assert!(!errors.iter().any(|x| x.to_string() == format!("Missing #[child_parents(...)] instruction for {}", ty)))
+            true => assert!(errors.iter().any(|x| x.to_string() == format!("Missing #[child_parents(...)] instruction for {}, which should specify types for 'base', 'base.base', 'child'", ty))),
+            false => assert!(!errors.iter().any(|x| x.to_string().starts_with(&format!("Missing #[child_parents(...)] instruction for {},", ty))))
         }
     }
 }
@@ -1030,6 +1060,22 @@ fn missing_child_parents_instruction(code_fragment: TokenStream, errs: Vec<(&str
         x: i32
     }
 }, vec!["literal", "pattern", "type_hint", "ghosts"]; "8")]
+#[test_case(quote! {
+    #[map_owned(i32| _ => todo!())]
+    enum Test {
+        #[o2o(map_opt)]
+        #[literal(123)]
+        Var
+    }
+}, vec!["map_opt"]; "9")]
+#[test_case(quote! {
+    #[map_owned(i32| _ => todo!())]
+    enum Test {
+        #[o2o(map_items)]
+        #[literal(123)]
+        Var
+    }
+}, vec!["map_items"]; "10")]
 fn member_instr_on_wrong_member(code_fragment: TokenStream, errs: Vec<&str>) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
@@ -1062,12 +1108,12 @@ fn member_instr_on_wrong_member(code_fragment: TokenStream, errs: Vec<&str>) {
         child_int: i32,
     }
 }, vec![
-    ("base", "EntityDto", true),
-    ("base", "EntityModel", true),
-    ("base.base", "EntityDto", true),
-    ("base.base", "EntityModel", true),
-    ("child", "EntityDto", true),
-    ("child", "EntityModel", true),
+    ("base", "base_base_int", "EntityDto", true),
+    ("base", "base_base_int", "EntityModel", true),
+    ("base.base", "base_base_int", "EntityDto", true),
+    ("base.base", "base_base_int", "EntityModel", true),
+    ("child", "child_int", "EntityDto", true),
+    ("child", "child_int", "EntityModel", true),
 ]; "1")]
 #[test_case(quote! {
     #[map(EntityDto)]
@@ -1080,12 +1126,12 @@ fn member_instr_on_wrong_member(code_fragment: TokenStream, errs: Vec<&str>) {
         child_int: i32,
     }
 }, vec![
-    ("base", "EntityDto", false),
-    ("base", "EntityModel", false),
-    ("base.base", "EntityDto", true),
-    ("base.base", "EntityModel", true),
-    ("child", "EntityDto", true),
-    ("child", "EntityModel", true),
+    ("base", "base_base_int", "EntityDto", false),
+    ("base", "base_base_int", "EntityModel", false),
+    ("base.base", "base_base_int", "EntityDto", true),
+    ("base.base", "base_base_int", "EntityModel", true),
+    ("child", "child_int", "EntityDto", true),
+    ("child", "child_int", "EntityModel", true),
 ]; "2")]
 #[test_case(quote! {
     #[map(EntityDto)]
@@ -1099,22 +1145,22 @@ fn member_instr_on_wrong_member(code_fragment: TokenStream, errs: Vec<&str>) {
         child_int: i32,
     }
 }, vec![
-    ("base", "EntityDto", false),
-    ("base", "EntityModel", true),
-    ("base.base", "EntityDto", true),
-    ("base.base", "EntityModel", true),
-    ("child", "EntityDto", true),
-    ("child", "EntityModel", false),
+    ("base", "base_base_int", "EntityDto", false),
+    ("base", "base_base_int", "EntityModel", true),
+    ("base.base", "base_base_int", "EntityDto", true),
+    ("base.base", "base_base_int", "EntityModel", true),
+    ("child", "child_int", "EntityDto", true),
+    ("child", "child_int", "EntityModel", false),
 ]; "3")]
-fn incomplete_child_parents_instruction(code_fragment: TokenStream, errs: Vec<(&str, &str, bool)>) {
+fn incomplete_child_parents_instruction(code_fragment: TokenStream, errs: Vec<(&str, &str, &str, bool)>) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
     let errors: Vec<Error> = get_error_iter(output).collect();
 
-    for (field, ty, should_contain) in errs {
+    for (path, member, ty, should_contain) in errs {
         match should_contain {
-            true => assert!(errors.iter().any(|x| x.to_string() == format!("Missing '{}: [Type Path]' instruction for type {}", field, ty))),
-            false => assert!(!errors.iter().any(|x| x.to_string() == format!("Missing '{}: [Type Path]' instruction for type {}", field, ty))),
+            true => assert!(errors.iter().any(|x| x.to_string() == format!("Missing '{}: [Type Path]' instruction for type {}, required by member '{}'", path, ty, member))),
+            false => assert!(!errors.iter().any(|x| x.to_string() == format!("Missing '{}: [Type Path]' instruction for type {}, required by member '{}'", path, ty, member))),
         }
     }
 }
@@ -1778,156 +1824,1579 @@ fn permeating_repeat(code_fragment: TokenStream) {
 
 // endregion: permeating_repeat
 
-// region: item_attributes
+// region: map_items
 
 #[test_case(quote!{
-    #[map(TestDto|
-        impl_attribute(impl_attribute(param)),
-        attribute(attribute(param)),
-        inner_attribute(inner_param(param))
-    )]
-    #[into_existing(TestDto|
-        impl_attribute(impl_attribute(param)),
-        attribute(attribute(param)),
-        inner_attribute(inner_param(param))
-    )]
+    #[map(TestDto)]
     struct Test {
-        x: i32,
+        #[o2o(map_items(keys, items))]
+        x1: Vec<i32>,
     }
-},
-quote!{
-    #[impl_attribute(param)]
-    impl ::core::convert::From<TestDto> for Test {
-        #[attribute(param)]
-        fn from(value: TestDto) -> Test {
-            #![inner_param(param)]
-            Test { x: value.x, }
-        }
+}, "#[map_items] of 'items' is not supported. Supported options are: keys, values"; "1")]
+#[test_case(quote!{
+    #[map(TestDto)]
+    struct Test {
+        #[o2o(map_items(TestDto| keys, items))]
+        x1: Vec<i32>,
     }
+}, "#[map_items] of 'items' is not supported. Supported options are: keys, values"; "2")]
+fn map_items(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, false);
 
-    #[impl_attribute(param)]
-    impl ::core::convert::From<&TestDto> for Test {
-        #[attribute(param)]
-        fn from(value: &TestDto) -> Test {
-            #![inner_param(param)]
-            Test { x: value.x, }
-        }
-    }
-    #[impl_attribute(param)]
-    impl ::core::convert::Into<TestDto> for Test {
-        #[attribute(param)]
-        fn into(self) -> TestDto {
-            #![inner_param(param)]
-            TestDto { x: self.x, }
-        }
-    }
-    #[impl_attribute(param)]
-    impl ::core::convert::Into<TestDto> for &Test {
-        #[attribute(param)]
-        fn into(self) -> TestDto {
-            #![inner_param(param)]
-            TestDto { x: self.x, }
-        }
-    }
-    #[impl_attribute(param)]
-    impl o2o::traits::IntoExisting<TestDto> for Test {
-        #[attribute(param)]
-        fn into_existing(self, other: &mut TestDto) {
-            #![inner_param(param)]
-            other.x = self.x;
-        }
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[map(TestDto)]
+    struct Test {
+        #[o2o(map_opt)]
+        #[o2o(map_items)]
+        x1: Option<Vec<i32>>,
     }
-    #[impl_attribute(param)]
-    impl o2o::traits::IntoExisting<TestDto> for &Test {
-        #[attribute(param)]
-        fn into_existing(self, other: &mut TestDto) {
-            #![inner_param(param)]
-            other.x = self.x;
-        }
+}, "Instructions 'map_opt' and 'map_items' can't be applied to the same member."; "1")]
+#[test_case(quote!{
+    #[map(TestDto)]
+    #[map(TestDto2)]
+    struct Test {
+        #[o2o(map_opt(TestDto2))]
+        #[o2o(map_items)]
+        x1: Option<Vec<i32>>,
     }
-}; "1")]
+}, "Instructions 'map_opt' and 'map_items' can't be applied to the same member."; "2")]
+fn map_items_validation(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
 #[test_case(quote!{
-    #[try_map(TestDto, String|
-        impl_attribute(impl_attribute(param)),
-        attribute(attribute(param)),
-        inner_attribute(inner_param(param))
-    )]
-    #[try_into_existing(TestDto, String|
-        impl_attribute(impl_attribute(param)),
-        attribute(attribute(param)),
-        inner_attribute(inner_param(param))
-    )]
+    #[try_from_owned(TestDto, String)]
     struct Test {
-        x: i32,
+        #[o2o(map_items)]
+        x1: Vec<i32>,
+        #[o2o(map_items(values))]
+        x2: Map<String, i32>,
     }
 },
 quote!{
-    #[impl_attribute(param)]
     impl ::core::convert::TryFrom<TestDto> for Test {
         type Error = String;
-        #[attribute(param)]
         fn try_from(value: TestDto) -> ::core::result::Result<Test, String> {
-            #![inner_param(param)]
-            Ok(Test { x: value.x, })
+            Ok(Test {
+                x1: value.x1.into_iter().map(|x| x.try_into().map_err(::core::convert::Into::<String>::into)).collect::<::core::result::Result<_, String>>()?,
+                x2: value.x2.into_iter().map(|(k, v)| ::core::result::Result::<_, String>::Ok((k, v.try_into()?))).collect::<::core::result::Result<_, String>>()?,
+            })
         }
     }
-    #[impl_attribute(param)]
-    impl ::core::convert::TryFrom<&TestDto> for Test {
-        type Error = String;
-        #[attribute(param)]
-        fn try_from(value: &TestDto) -> ::core::result::Result<Test, String> {
-            #![inner_param(param)]
-            Ok(Test { x: value.x, })
-        }
+}; "1")]
+fn map_items_output(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: map_items
+
+// region: shape_check
+
+#[test_case(quote!{
+    #[map(TestDto| check_shape)]
+    enum Test {}
+}, "Shape checks are only applicable to structs."; "1")]
+#[test_case(quote!{
+    #[map(TestDto| check_shape)]
+    struct Test<T> {
+        x: T,
     }
-    #[impl_attribute(param)]
-    impl ::core::convert::TryInto<TestDto> for Test {
-        type Error = String;
-        #[attribute(param)]
-        fn try_into(self) -> ::core::result::Result<TestDto, String> {
-            #![inner_param(param)]
-            Ok(TestDto { x: self.x, })
-        }
+}, "Shape checks are not supported for generic structs."; "2")]
+#[test_case(quote!{
+    #[map(TestDto as ()| check_shape)]
+    struct Test {
+        x: i32,
     }
-    #[impl_attribute(param)]
-    impl ::core::convert::TryInto<TestDto> for &Test {
-        type Error = String;
-        #[attribute(param)]
-        fn try_into(self) -> ::core::result::Result<TestDto, String> {
-            #![inner_param(param)]
-            Ok(TestDto { x: self.x, })
-        }
+}, "Shape checks are not supported together with type hints."; "3")]
+#[test_case(quote!{
+    #[map((i32, i32)| check_shape)]
+    struct Test(i32, i32);
+}, "Shape checks are not supported together with type hints."; "4")]
+#[test_case(quote!{
+    #[map(TestDto| check_shape, check_shape)]
+    struct Test(i32);
+}, "Instruction parameter 'check_shape' was already set."; "5")]
+fn shape_check(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: shape_check
+
+// region: rename_all
+
+#[test_case(quote!{
+    #[map(TestDto| rename_all("kebab-case"))]
+    struct Test {
+        x: i32,
     }
-    #[impl_attribute(param)]
-    impl o2o::traits::TryIntoExisting<TestDto> for Test {
-        type Error = String;
-        #[attribute(param)]
-        fn try_into_existing(self, other: &mut TestDto) -> ::core::result::Result<(), String> {
-            #![inner_param(param)]
-            other.x = self.x;
-            Ok(())
-        }
+}, "Rename rule 'kebab-case' is not supported. Supported rules are: lowercase, UPPERCASE, PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, or a template containing '{}'"; "1")]
+#[test_case(quote!{
+    #[map(TestDto| rename_all("snake_case"), rename_all("{}_dto"))]
+    struct Test {
+        x: i32,
     }
-    #[impl_attribute(param)]
-    impl o2o::traits::TryIntoExisting<TestDto> for &Test {
-        type Error = String;
-        #[attribute(param)]
-        fn try_into_existing(self, other: &mut TestDto) -> ::core::result::Result<(), String> {
-            #![inner_param(param)]
-            other.x = self.x;
-            Ok(())
-        }
+}, "Instruction parameter 'rename_all' was already set."; "2")]
+fn rename_all(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, false);
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[map_owned(TestDto| rename_all("{}-x"))]
+    struct Test {
+        a: i32,
     }
-}; "2")]
-fn item_attributes(code_fragment: TokenStream, expected_output: TokenStream) {
+}, "Rename rule turns 'a' into 'a-x', which is not a valid identifier."; "1")]
+#[test_case(quote!{
+    #[map_owned(TestDto| rename_all("lowercase"))]
+    enum Test {
+        A { Type: i32 },
+    }
+}, "Rename rule turns 'Type' into 'type', which is not a valid identifier."; "2")]
+fn rename_all_validation(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[map_owned(TestDto| rename_all("{}-x"))]
+    struct Test {
+        #[map(b)]
+        a: i32,
+    }
+}; "1")]
+fn rename_all_overridden(code_fragment: TokenStream) {
     let input: DeriveInput = syn::parse2(code_fragment).unwrap();
     let output = derive(&input);
 
     assert!(output.is_ok());
-    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
 }
 
-// endregion: item_attributes
+// endregion: rename_all
+
+// region: standalone_fn
+
+#[test_case(quote!{
+    #[map(TestDto| fn(to_test_dto))]
+    struct Test {
+        x: i32,
+    }
+}, "Standalone functions are only supported for instructions that produce a single conversion (e.g. 'from_owned', 'ref_into', 'owned_into_existing')."; "1")]
+#[test_case(quote!{
+    #[owned_into(TestDto| fn(to_test_dto))]
+    #[ref_into(TestDto| fn(to_test_dto))]
+    struct Test {
+        x: i32,
+    }
+}, "Function name here must be unique."; "2")]
+#[test_case(quote!{
+    #[from_owned(TestDto| fn(from_test_dto), fn(from_test_dto_2))]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'fn' was already set."; "3")]
+fn standalone_fn(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: standalone_fn
+
+// region: remote
+
+#[test_case(quote!{
+    #[o2o(remote(foreign::Test))]
+    #[o2o(remote(foreign::AnotherTest))]
+    #[from_owned(TestDto| fn(from_test_dto))]
+    struct Test {
+        x: i32,
+    }
+}, "There can be at most one #[o2o(remote(...))] instruction."; "1")]
+fn remote(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+// endregion: remote
+
+// region: collect_errors
+
+#[test_case(quote!{
+    #[from(TestDto| collect_errors)]
+    struct Test {
+        x: i32,
+    }
+}, "Error collection is only applicable to fallible instructions."; "1")]
+#[test_case(quote!{
+    #[try_into_existing(TestDto, String| collect_errors)]
+    struct Test {
+        x: i32,
+    }
+}, "Error collection is not supported for 'into_existing' instructions."; "2")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| collect_errors)]
+    #[child_parents(child: Child)]
+    struct Test {
+        #[child(child)]
+        x: i32,
+    }
+}, "Error collection is not supported together with child and parent instructions."; "3")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| collect_errors)]
+    enum Test {
+        Var
+    }
+}, "Error collection is only applicable to structs."; "4")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| collect_errors, collect_errors(Errors))]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'collect_errors' was already set."; "5")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| collect_errors)]
+    struct Test {
+        #[from(~.parse::<i32>()? + 1)]
+        x: i32,
+    }
+}, "Error collection only supports '?' at the end of member actions."; "6")]
+#[test_case(quote!{
+    #[try_into(TestDto, String| collect_errors)]
+    #[ghosts(y: { @.x.parse::<i32>()? + 1 })]
+    struct Test {
+        x: String,
+    }
+}, "Error collection only supports '?' at the end of ghost actions."; "7")]
+fn collect_errors(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[owned_try_into(TestDto, String| collect_errors(Errors))]
+    struct Test {
+        #[into(~.parse().map_err(|_| "not a number".to_string())?)]
+        x: String,
+        y: i32,
+    }
+},
+quote!{
+    impl ::core::convert::TryInto<TestDto> for Test {
+        type Error = Errors;
+        fn try_into(self) -> ::core::result::Result<TestDto, Errors> {
+            let mut errors: Errors = ::core::default::Default::default();
+            let o2o_f0 = match self.x.parse().map_err(|_| "not a number".to_string()) {
+                ::core::result::Result::Ok(x) => ::core::option::Option::Some(x),
+                ::core::result::Result::Err(e) => {
+                    <Errors as o2o::traits::ErrorCollector<String>>::push(&mut errors, "x", ::core::convert::From::from(e));
+                    ::core::option::Option::None
+                }
+            };
+            let o2o_f1 = self.y;
+            if !o2o::traits::ErrorCollector::is_empty(&errors) {
+                return Err(errors);
+            }
+            Ok(TestDto {
+                x: o2o_f0.unwrap(),
+                y: o2o_f1,
+            })
+        }
+    }
+}; "1")]
+fn collect_errors_output(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: collect_errors
+
+// region: error_paths
+
+#[test_case(quote!{
+    #[from(TestDto| error_paths)]
+    struct Test {
+        x: i32,
+    }
+}, "Error paths are only applicable to fallible instructions."; "1")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| error_paths, collect_errors)]
+    struct Test {
+        x: i32,
+    }
+}, "Error paths are not supported together with error collection."; "2")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| error_paths)]
+    enum Test {
+        Var
+    }
+}, "Error paths are only applicable to structs."; "3")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| error_paths, error_paths)]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'error_paths' was already set."; "4")]
+fn error_paths(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[try_from_owned(TestDto, String| error_paths)]
+    struct Test {
+        x: i32,
+        #[from(~.parse().map_err(|_| "not a number".to_string())?)]
+        y: i32,
+        #[parent]
+        z: Child,
+    }
+},
+quote!{
+    impl ::core::convert::TryFrom<TestDto> for Test {
+        type Error = o2o::traits::FieldError<String>;
+        fn try_from(value: TestDto) -> ::core::result::Result<Test, o2o::traits::FieldError<String> > {
+            Ok(Test {
+                x: value.x,
+                y: value.y.parse().map_err(|_| "not a number".to_string()).map_err(|e| o2o::traits::FieldError::<String>::new("y", ::core::convert::From::from(e)))?,
+                z: (&value).try_into().map_err(|e| o2o::traits::IntoFieldError::<String>::into_field_error(e, "z"))?,
+            })
+        }
+    }
+}; "1")]
+fn error_paths_output(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: error_paths
+
+// region: map_err
+
+#[test_case(quote!{
+    #[from(TestDto| map_err(Error::new))]
+    struct Test {
+        x: i32,
+    }
+}, "Error mapping is only applicable to fallible instructions."; "1")]
+#[test_case(quote!{
+    #[try_from(TestDto, String| map_err(Error::new), map_err(Error::new))]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'map_err' was already set."; "2")]
+#[test_case(quote!{
+    #[from_owned(TestDto)]
+    struct Test {
+        #[from(~.parse().unwrap(), map_err = Error::new)]
+        x: i32,
+    }
+}, "Member instruction parameter 'map_err' is not applicable to infallible instruction #[from_owned(TestDto...)]."; "3")]
+#[test_case(quote!{
+    #[try_from_owned(TestDto, String)]
+    #[owned_into(TestDto)]
+    struct Test {
+        #[map(~.parse()?, map_err = Error::new)]
+        x: i32,
+    }
+}, "Member instruction parameter 'map_err' is not applicable to infallible instruction #[owned_into(TestDto...)]."; "4")]
+#[test_case(quote!{
+    #[try_from_owned(TestDto, String)]
+    struct Test {
+        #[from(map_err = Error::new)]
+        x: i32,
+    }
+}, "Member instruction parameter 'map_err' needs an action that ends with '?' or evaluates to a Result."; "5")]
+#[test_case(quote!{
+    #[try_from_owned(TestDto, String)]
+    struct Test {
+        #[from(y, map_err = Error::new)]
+        x: i32,
+    }
+}, "Member instruction parameter 'map_err' needs an action that ends with '?' or evaluates to a Result."; "6")]
+fn map_err(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: map_err
+
+// region: nested
+
+#[test_case(quote!{
+    #[owned_into_existing(TestDto)]
+    struct Test {
+        #[into_existing(nested = true)]
+        x: Inner,
+    }
+}, quote!{
+    impl o2o::traits::IntoExisting<TestDto> for Test {
+        fn into_existing(self, other: &mut TestDto) {
+            (self.x).into_existing(&mut other.x);
+        }
+    }
+}; "1")]
+#[test_case(quote!{
+    #[owned_into_existing(TestDto)]
+    struct Test {
+        #[into_existing(y, nested = true)]
+        x: Inner,
+    }
+}, quote!{
+    impl o2o::traits::IntoExisting<TestDto> for Test {
+        fn into_existing(self, other: &mut TestDto) {
+            (self.x).into_existing(&mut other.y);
+        }
+    }
+}; "2")]
+#[test_case(quote!{
+    #[owned_into_existing(TestDto)]
+    struct Test {
+        #[into_existing(nested)]
+        x: Inner,
+    }
+}, quote!{
+    impl o2o::traits::IntoExisting<TestDto> for Test {
+        fn into_existing(self, other: &mut TestDto) {
+            other.nested = self.x;
+        }
+    }
+}; "3")]
+#[test_case(quote!{
+    #[owned_into_existing(TestDto)]
+    struct Test {
+        #[into_existing(nested = false)]
+        x: Inner,
+    }
+}, quote!{
+    impl o2o::traits::IntoExisting<TestDto> for Test {
+        fn into_existing(self, other: &mut TestDto) {
+            other.x = self.x;
+        }
+    }
+}; "4")]
+fn nested(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: nested
+
+// region: ghost_builder
+
+#[test_case(quote!{
+    #[into(TestDto)]
+    #[ghosts(y as i32)]
+    struct Test {
+        x: i32,
+    }
+}, "Ghost fields without default value are only supported by 'owned_into' instructions."; "1")]
+#[test_case(quote!{
+    #[owned_into(TestDto)]
+    #[ghosts(y as T)]
+    struct Test<T> {
+        x: T,
+    }
+}, "Ghost fields without default value are not supported for generic types."; "2")]
+#[test_case(quote!{
+    #[owned_into(TestDto)]
+    #[ghosts(1 as i32)]
+    struct Test(i32);
+}, "Ghost fields without default value must be named."; "3")]
+#[test_case(quote!{
+    #[owned_into(a::TestDto)]
+    #[owned_into(b::TestDto)]
+    #[ghosts(y as i32)]
+    struct Test {
+        x: i32,
+    }
+}, "Builder method 'into_test_dto_builder' would be generated more than once. Use fn(...) to give one of the builders another name."; "4")]
+#[test_case(quote!{
+    #[owned_into(TestDto)]
+    #[child_parents(a: A, b: B)]
+    #[ghosts(a@id as i32, b@id as i32)]
+    struct Test {
+        x: i32,
+    }
+}, "Ghost fields without default value become builder methods, so their names must be unique. 'id' is used more than once."; "5")]
+#[test_case(quote!{
+    #[owned_into(TestDto)]
+    #[ghosts(build as i32)]
+    struct Test {
+        x: i32,
+    }
+}, "Ghost field without default value can't be named 'build', because the builder uses that name."; "6")]
+#[test_case(quote!{
+    #[owned_into(TestDto)]
+    #[ghosts(value as i32)]
+    struct Test {
+        x: i32,
+    }
+}, "Ghost field without default value can't be named 'value', because the builder uses that name."; "7")]
+fn ghost_builder(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[owned_into(a::TestDto)]
+    #[owned_try_into(a::TestDto, String)]
+    #[owned_into(b::TestDto| fn(into_b))]
+    #[ghosts(y as i32)]
+    struct Test {
+        x: i32,
+    }
+}; "1")]
+fn ghost_builder_names(code_fragment: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input).unwrap().to_string();
+
+    assert!(output.contains("fn into_test_dto_builder (self) -> TestIntoTestDtoBuilder"));
+    assert!(output.contains("fn try_into_test_dto_builder (self) -> TestTryIntoTestDtoBuilder"));
+    assert!(output.contains("fn into_b (self) -> TestIntoBBuilder"));
+}
+
+// endregion: ghost_builder
+
+// region: with
+
+#[test_case(quote!{
+    #[into_existing(TestDto| with(ctx: &Context))]
+    struct Test {
+        x: i32,
+    }
+}, "Context arguments are not supported for 'into_existing' instructions."; "1")]
+#[test_case(quote!{
+    #[from(TestDto| with(ctx: &Context), with(ctx: &Context))]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'with' was already set."; "2")]
+fn with(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: with
+
+// region: async
+
+#[test_case(quote!{
+    #[o2o(async_try_from(TestDto, String| with(ctx: &Context)))]
+    struct Test {
+        x: i32,
+    }
+}, "Context arguments are not supported for async instructions."; "1")]
+#[test_case(quote!{
+    #[o2o(async_try_into(TestDto, String| collect_errors))]
+    struct Test {
+        x: i32,
+    }
+}, "Async instructions don't support 'collect_errors' and 'error_paths' instruction parameters."; "2")]
+#[test_case(quote!{
+    #[o2o(async_try_from(TestDto, String| error_paths))]
+    struct Test {
+        x: i32,
+    }
+}, "Async instructions don't support 'collect_errors' and 'error_paths' instruction parameters."; "3")]
+#[test_case(quote!{
+    #[o2o(async_try_from(TestDto))]
+    struct Test {
+        x: i32,
+    }
+}, "Error type should be specified for fallible instruction."; "4")]
+fn async_instrs(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+// endregion: async
+
+// region: diff
+
+#[test_case(quote!{
+    #[into(TestDto| diff)]
+    struct Test {
+        x: i32,
+    }
+}, "Diff is only applicable to infallible 'into_existing' instructions."; "1")]
+#[test_case(quote!{
+    #[try_into_existing(TestDto, String| diff)]
+    struct Test {
+        x: i32,
+    }
+}, "Diff is only applicable to infallible 'into_existing' instructions."; "2")]
+#[test_case(quote!{
+    #[into_existing(TestDto| diff, return TestDto { x: 1 })]
+    struct Test {
+        x: i32,
+    }
+}, "Diff is not supported together with quick returns."; "3")]
+#[test_case(quote!{
+    #[into_existing(TestDto| diff)]
+    enum Test {
+        Var
+    }
+}, "Diff is only applicable to structs."; "4")]
+#[test_case(quote!{
+    #[into_existing(TestDto| diff, diff)]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'diff' was already set."; "5")]
+fn diff(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: diff
+
+// region: patch
+
+#[test_case(quote!{
+    #[into(TestDto| patch)]
+    struct Test {
+        x: Option<i32>,
+    }
+}, "Patch mode is only applicable to 'into_existing' instructions."; "1")]
+#[test_case(quote!{
+    #[into_existing(TestDto| patch)]
+    enum Test {
+        Var
+    }
+}, "Patch mode is only applicable to structs."; "2")]
+#[test_case(quote!{
+    #[into_existing(TestDto)]
+    struct Test {
+        #[map(skip_if = Option::is_none, ~.unwrap())]
+        x: Option<i32>,
+    }
+}, "Skip conditions are only applicable to 'into_existing' member instructions."; "3")]
+#[test_case(quote!{
+    #[into_existing(TestDto| patch, patch)]
+    struct Test {
+        x: Option<i32>,
+    }
+}, "Instruction parameter 'patch' was already set."; "4")]
+fn patch(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test]
+fn patch_skip_if_output() {
+    let code_fragment = quote!{
+        #[owned_into_existing(TestDto| patch)]
+        struct Test {
+            #[into_existing(patch = false)]
+            x: i32,
+            #[into_existing(skip_if = Option::is_none, ~.unwrap())]
+            y: Option<i32>,
+            #[into_existing(w, patch = false, nested = true)]
+            z: Inner,
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    let expected_output = quote!{
+        impl o2o::traits::IntoExisting<TestDto> for Test {
+            fn into_existing(self, other: &mut TestDto) {
+                other.x = self.x;
+                if !(Option::is_none)(&self.y) { other.y = self.y.unwrap(); }
+                (self.z).into_existing(&mut other.w);
+            }
+        }
+    };
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+#[test]
+fn ref_patch_output() {
+    let code_fragment = quote!{
+        #[ref_into_existing(TestDto| patch)]
+        struct Test {
+            x: Option<String>,
+            #[into(~.map(|x| x as i16))]
+            y: Option<u8>,
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    let expected_output = quote!{
+        impl o2o::traits::IntoExisting<TestDto> for &Test {
+            fn into_existing(self, other: &mut TestDto) {
+                if let Some(v) = &self.x { other.x = v.clone(); }
+                if let Some(v) = self.y.map(|x| x as i16) { other.y = v; }
+            }
+        }
+    };
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: patch
+
+// region: default_init
+
+#[test_case(quote!{
+    #[from(TestDto| default_init)]
+    struct Test {
+        x: i32,
+    }
+}, "Default initialization is only applicable to 'into' instructions."; "1")]
+#[test_case(quote!{
+    #[into(TestDto| default_init)]
+    enum Test {
+        Var
+    }
+}, "Default initialization is only applicable to structs."; "2")]
+#[test_case(quote!{
+    #[into(TestDto| default_init, default_init)]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'default_init' was already set."; "3")]
+#[test_case(quote!{
+    #[owned_into(TestDto| default_init)]
+    #[ref_into(TestDto)]
+    struct Test {
+        #[child(base)]
+        x: i32,
+    }
+}, "Missing #[child_parents(...)] instruction for TestDto, which should specify types for 'base'"; "4")]
+fn default_init(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test]
+fn default_init_output() {
+    let code_fragment = quote!{
+        #[owned_into(TestDto| default_init)]
+        struct Test {
+            x: i32,
+            #[child(base.inner)]
+            y: i32,
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    let expected_output = quote!{
+        impl ::core::convert::Into<TestDto> for Test {
+            fn into(self) -> TestDto {
+                let mut obj: TestDto = Default::default();
+                obj.x = self.x;
+                obj.base.inner.y = self.y;
+                obj
+            }
+        }
+    };
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: default_init
+
+// region: impl_from
+
+#[test_case(quote!{
+    #[from(TestDto| impl_from)]
+    struct Test {
+        x: i32,
+    }
+}, "From implementations are only applicable to 'into' instructions."; "1")]
+#[test_case(quote!{
+    #[into_existing(TestDto| impl_from)]
+    struct Test {
+        x: i32,
+    }
+}, "From implementations are only applicable to 'into' instructions."; "2")]
+#[test_case(quote!{
+    #[owned_into(TestDto| impl_from, fn(to_test_dto))]
+    struct Test {
+        x: i32,
+    }
+}, "From implementations are not supported together with context arguments, standalone functions and async instructions."; "3")]
+#[test_case(quote!{
+    #[into(TestDto| impl_from, impl_from)]
+    struct Test {
+        x: i32,
+    }
+}, "Instruction parameter 'impl_from' was already set."; "4")]
+fn impl_from(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: impl_from
+
+// region: transparent
+
+#[test_case(quote!{
+    #[o2o(transparent)]
+    #[map(i32)]
+    struct Test {
+        x: i32,
+        y: i32,
+    }
+}, "Transparent mode is only applicable to structs with a single field."; "1")]
+#[test_case(quote!{
+    #[o2o(transparent)]
+    #[map(TestDto)]
+    enum Test {
+        A(i32),
+    }
+}, "Transparent mode is only applicable to structs."; "2")]
+fn transparent(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+#[test_case(quote!{
+    #[o2o(transparent)]
+    #[from_owned(std::string::String)]
+    struct Test(String);
+}, quote!{
+    impl ::core::convert::From<std::string::String> for Test {
+        fn from(value: std::string::String) -> Test {
+            Test(value)
+        }
+    }
+}; "1")]
+#[test_case(quote!{
+    #[o2o(transparent)]
+    #[from_owned(Vec<String>)]
+    struct Test(std::vec::Vec<std::string::String>);
+}, quote!{
+    impl ::core::convert::From<Vec<String> > for Test {
+        fn from(value: Vec<String>) -> Test {
+            Test(value)
+        }
+    }
+}; "2")]
+#[test_case(quote!{
+    #[o2o(transparent)]
+    #[from_owned(a::Id)]
+    struct Test(b::Id);
+}, quote!{
+    impl ::core::convert::From<a::Id> for Test {
+        fn from(value: a::Id) -> Test {
+            Test(value.0,)
+        }
+    }
+}; "3")]
+fn transparent_output(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: transparent
+
+// region: convert_fields
+
+#[test_case(quote!{
+    #[map(TestDto| convert_fields)]
+    struct Test {
+        x: i32,
+    }
+}, "Field conversion is only applicable to enums."; "1")]
+#[test_case(quote!{
+    #[map(TestDto| convert_fields, convert_fields)]
+    enum Test {
+        A(i32),
+    }
+}, "Instruction parameter 'convert_fields' was already set."; "2")]
+fn convert_fields(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test]
+fn convert_fields_fallible_output() {
+    let code_fragment = quote!{
+        #[try_from_owned(TestDto, String| convert_fields)]
+        enum Test {
+            A(#[from(~)] i32, Inner),
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    let expected_output = quote!{
+        impl ::core::convert::TryFrom<TestDto> for Test {
+            type Error = String;
+            fn try_from(value: TestDto) -> ::core::result::Result<Test, String> {
+                Ok(match value {
+                    TestDto::A(f0, f1,) => Test::A(f0, ::core::convert::TryInto::try_into(f1)?,),
+                })
+            }
+        }
+    };
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: convert_fields
+
+// region: tag
+
+#[test_case(quote!{
+    #[owned_into(TestDto| tag(kind))]
+    struct Test {
+        x: i32,
+    }
+}, "Tag instructions are only applicable to enums."; "1")]
+#[test_case(quote!{
+    #[into_existing(TestDto| tag(kind))]
+    enum Test {
+        #[literal("a")]
+        A,
+    }
+}, "Tag instructions are not supported for 'into_existing' instructions."; "2")]
+#[test_case(quote!{
+    #[owned_into(TestDto| tag(kind))]
+    enum Test {
+        #[literal("a")]
+        A(i32),
+    }
+}, "Tagged conversions only support unit variants and variants with named fields."; "3")]
+#[test_case(quote!{
+    #[owned_into(TestDto| tag(kind))]
+    enum Test {
+        #[literal("a")]
+        A,
+        B { x: i32 },
+    }
+}, "Variant 'B' should have #[literal(...)] instruction for tagged conversion with TestDto"; "4")]
+#[test_case(quote!{
+    #[from_owned(TestDto| tag(kind), tag(kind), _ => todo!())]
+    enum Test {
+        #[literal("a")]
+        A,
+    }
+}, "Instruction parameter 'tag' was already set."; "5")]
+#[test_case(quote!{
+    #[from_owned(TestDto| tag(kind), _ => todo!())]
+    enum Test {
+        #[literal("a")]
+        A,
+    }
+}, "Tagged conversions from structs are only supported by fallible instructions (e.g. 'try_from')."; "6")]
+#[test_case(quote!{
+    #[try_from_ref(TestDto, String| tag(kind))]
+    enum Test {
+        #[literal("a")]
+        A,
+    }
+}, "Tagged conversions from structs require a default case (e.g. '_ => Err(...)?')."; "7")]
+fn tag(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+#[test]
+fn tag_fallible_output() {
+    let code_fragment = quote!{
+        #[try_from_owned(TestDto, String| tag(kind), _ => todo!())]
+        enum Test {
+            #[literal("a")]
+            A { x: i32 },
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    let expected_output = quote!{
+        impl ::core::convert::TryFrom<TestDto> for Test {
+            type Error = String;
+            fn try_from(value: TestDto) -> ::core::result::Result<Test, String> {
+                Ok(match &*value.kind {
+                    "a" => Test::A { x: value.x.ok_or(o2o::traits::MissingFieldError("x"))?, },
+                    _ => todo!()
+                })
+            }
+        }
+    };
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: tag
+
+// region: item_attributes
+
+#[test_case(quote!{
+    #[map(TestDto|
+        impl_attribute(impl_attribute(param)),
+        attribute(attribute(param)),
+        inner_attribute(inner_param(param))
+    )]
+    #[into_existing(TestDto|
+        impl_attribute(impl_attribute(param)),
+        attribute(attribute(param)),
+        inner_attribute(inner_param(param))
+    )]
+    struct Test {
+        x: i32,
+    }
+},
+quote!{
+    #[impl_attribute(param)]
+    impl ::core::convert::From<TestDto> for Test {
+        #[attribute(param)]
+        fn from(value: TestDto) -> Test {
+            #![inner_param(param)]
+            Test { x: value.x, }
+        }
+    }
+
+    #[impl_attribute(param)]
+    impl ::core::convert::From<&TestDto> for Test {
+        #[attribute(param)]
+        fn from(value: &TestDto) -> Test {
+            #![inner_param(param)]
+            Test { x: value.x, }
+        }
+    }
+    #[impl_attribute(param)]
+    impl ::core::convert::Into<TestDto> for Test {
+        #[attribute(param)]
+        fn into(self) -> TestDto {
+            #![inner_param(param)]
+            TestDto { x: self.x, }
+        }
+    }
+    #[impl_attribute(param)]
+    impl ::core::convert::Into<TestDto> for &Test {
+        #[attribute(param)]
+        fn into(self) -> TestDto {
+            #![inner_param(param)]
+            TestDto { x: self.x, }
+        }
+    }
+    #[impl_attribute(param)]
+    impl o2o::traits::IntoExisting<TestDto> for Test {
+        #[attribute(param)]
+        fn into_existing(self, other: &mut TestDto) {
+            #![inner_param(param)]
+            other.x = self.x;
+        }
+    }
+    #[impl_attribute(param)]
+    impl o2o::traits::IntoExisting<TestDto> for &Test {
+        #[attribute(param)]
+        fn into_existing(self, other: &mut TestDto) {
+            #![inner_param(param)]
+            other.x = self.x;
+        }
+    }
+}; "1")]
+#[test_case(quote!{
+    #[try_map(TestDto, String|
+        impl_attribute(impl_attribute(param)),
+        attribute(attribute(param)),
+        inner_attribute(inner_param(param))
+    )]
+    #[try_into_existing(TestDto, String|
+        impl_attribute(impl_attribute(param)),
+        attribute(attribute(param)),
+        inner_attribute(inner_param(param))
+    )]
+    struct Test {
+        x: i32,
+    }
+},
+quote!{
+    #[impl_attribute(param)]
+    impl ::core::convert::TryFrom<TestDto> for Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_from(value: TestDto) -> ::core::result::Result<Test, String> {
+            #![inner_param(param)]
+            Ok(Test { x: value.x, })
+        }
+    }
+    #[impl_attribute(param)]
+    impl ::core::convert::TryFrom<&TestDto> for Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_from(value: &TestDto) -> ::core::result::Result<Test, String> {
+            #![inner_param(param)]
+            Ok(Test { x: value.x, })
+        }
+    }
+    #[impl_attribute(param)]
+    impl ::core::convert::TryInto<TestDto> for Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_into(self) -> ::core::result::Result<TestDto, String> {
+            #![inner_param(param)]
+            Ok(TestDto { x: self.x, })
+        }
+    }
+    #[impl_attribute(param)]
+    impl ::core::convert::TryInto<TestDto> for &Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_into(self) -> ::core::result::Result<TestDto, String> {
+            #![inner_param(param)]
+            Ok(TestDto { x: self.x, })
+        }
+    }
+    #[impl_attribute(param)]
+    impl o2o::traits::TryIntoExisting<TestDto> for Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_into_existing(self, other: &mut TestDto) -> ::core::result::Result<(), String> {
+            #![inner_param(param)]
+            other.x = self.x;
+            Ok(())
+        }
+    }
+    #[impl_attribute(param)]
+    impl o2o::traits::TryIntoExisting<TestDto> for &Test {
+        type Error = String;
+        #[attribute(param)]
+        fn try_into_existing(self, other: &mut TestDto) -> ::core::result::Result<(), String> {
+            #![inner_param(param)]
+            other.x = self.x;
+            Ok(())
+        }
+    }
+}; "2")]
+fn item_attributes(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+// endregion: item_attributes
+
+// region: infer_bounds
+
+#[test_case(quote!{
+    #[from_owned(Page<T>| infer_bounds(T))]
+    struct PageDto<U> {
+        #[from(~.into_iter().map(Into::into).collect())]
+        items: Vec<U>,
+        total: usize,
+    }
+},
+quote!{
+    impl<U, T> ::core::convert::From<Page<T> > for PageDto<U> where T: ::core::convert::Into<U> {
+        fn from(value: Page<T>) -> PageDto<U> {
+            PageDto {
+                items: value.items.into_iter().map(Into::into).collect(),
+                total: value.total,
+            }
+        }
+    }
+}; "1")]
+#[test_case(quote!{
+    #[try_from_ref(Page<T>, String| infer_bounds(T))]
+    struct PageDto<U> {
+        #[o2o(map_opt)]
+        first: Option<U>,
+        total: usize,
+    }
+},
+quote!{
+    impl<U, T> ::core::convert::TryFrom<&Page<T> > for PageDto<U>
+    where
+        for<'o2o_b> &'o2o_b T: ::core::convert::TryInto<U>,
+        for<'o2o_b> String: ::core::convert::From<<&'o2o_b T as ::core::convert::TryInto<U>>::Error>
+    {
+        type Error = String;
+        fn try_from(value: &Page<T>) -> ::core::result::Result<PageDto<U>, String> {
+            Ok(PageDto {
+                first: value.first.as_ref().map(::core::convert::TryInto::try_into).transpose()?,
+                total: value.total,
+            })
+        }
+    }
+}; "2")]
+#[test_case(quote!{
+    #[from_owned(Page<String>| infer_bounds)]
+    struct PageDto<U> {
+        #[from(~.into_iter().map(Into::into).collect())]
+        items: Vec<U>,
+        total: usize,
+    }
+},
+quote!{
+    impl<U> ::core::convert::From<Page<String> > for PageDto<U> where String: ::core::convert::Into<U> {
+        fn from(value: Page<String>) -> PageDto<U> {
+            PageDto {
+                items: value.items.into_iter().map(Into::into).collect(),
+                total: value.total,
+            }
+        }
+    }
+}; "3")]
+fn infer_bounds(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+#[test_case(quote!{
+    #[from_owned(Page<T, usize>| infer_bounds(T))]
+    struct PageDto<U> {
+        items: Vec<U>,
+    }
+}, "Bounds can't be inferred, because the instruction type has 2 generic type arguments, while the data type has 1 type parameters."; "1")]
+#[test_case(quote!{
+    #[from_owned(Page<T>| infer_bounds(V))]
+    struct PageDto<U> {
+        items: Vec<U>,
+    }
+}, "'V' is not a generic argument of the instruction type."; "2")]
+#[test_case(quote!{
+    #[from_owned(Page<U>| infer_bounds(U))]
+    struct PageDto<U> {
+        items: Vec<U>,
+    }
+}, "'U' is already a type parameter of the data type."; "3")]
+#[test_case(quote!{
+    #[from_owned(Page<T>| infer_bounds(T), infer_bounds)]
+    struct PageDto<U> {
+        items: Vec<U>,
+    }
+}, "Instruction parameter 'infer_bounds' was already set."; "4")]
+fn infer_bounds_errors(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: infer_bounds
+
+// region: mapping
+
+#[test_case(quote!{
+    #[from_owned(TestDto)]
+    struct Test {
+        x: i32,
+    }
+
+    #[owned_into(TestDto2)]
+    enum Test2 {
+        A,
+    }
+},
+quote!{
+    impl ::core::convert::From<TestDto> for Test {
+        fn from(value: TestDto) -> Test {
+            Test { x: value.x, }
+        }
+    }
+    impl ::core::convert::Into<TestDto2> for Test2 {
+        fn into(self) -> TestDto2 {
+            match self {
+                Test2::A => TestDto2::A,
+            }
+        }
+    }
+}; "1")]
+fn mapping(code_fragment: TokenStream, expected_output: TokenStream) {
+    let output = expand_mapping(code_fragment);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+#[test_case(quote!{
+    #[map(TestDto)]
+    struct Test {
+        x: i32,
+    }
+
+    #[try_from_owned(TestDto2)]
+    struct Test2 {
+        x: i32,
+    }
+}, "Error type should be specified for fallible instruction."; "1")]
+#[test_case(quote!{
+    #[o2o(remote(foreign::Entity))]
+    #[owned_into(foreign::Model| fn(into_model))]
+    struct EntityDef {
+        x: i32,
+    }
+}, "Remote types are not supported by 'o2o::mapping!', because its declarations are not emitted. Use #[derive(o2o)] on the shadow type instead."; "2")]
+fn mapping_errors(code_fragment: TokenStream, err: &str) {
+    let output = expand_mapping(code_fragment);
+    let message = get_error(output, true);
+
+    assert_eq!(message, err);
+}
+
+// endregion: mapping
+
+// region: convert
+
+#[test_case(quote!{
+    &person => PersonDto { id, name: ~.clone(), age: @.age + 1 }
+},
+quote!{
+    {
+        let o2o_value = &person;
+        PersonDto { id: o2o_value.id, name: o2o_value.name.clone(), age: o2o_value.age + 1, }
+    }
+}; "1")]
+#[test_case(quote!{
+    person => PersonDto { id, ..Default::default() }
+},
+quote!{
+    {
+        let o2o_value = person;
+        PersonDto { id: o2o_value.id, ..Default::default() }
+    }
+}; "2")]
+#[test_case(quote!{
+    values =>? Wrapper { 0: parse(@.0)? }
+},
+quote!{
+    ::core::result::Result::Ok({
+        let o2o_value = values;
+        Wrapper { 0: parse(o2o_value.0)?, }
+    })
+}; "3")]
+fn convert(code_fragment: TokenStream, expected_output: TokenStream) {
+    let output = expand_convert(code_fragment);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+#[test_case(quote!{
+    person PersonDto { id }
+}, "Expected '=>' followed by the target type."; "1")]
+#[test_case(quote!{
+    => PersonDto { id }
+}, "Expected source expression."; "2")]
+fn convert_errors(code_fragment: TokenStream, err: &str) {
+    let output = expand_convert(code_fragment);
+    let message = get_error(output, false);
+
+    assert_eq!(message, err);
+}
+
+// endregion: convert
+
+// region: from_str
+
+#[test_case(quote!{
+    #[o2o(from_str)]
+    #[o2o(display)]
+    enum Test {
+        #[literal("a")]
+        A,
+        B,
+    }
+},
+quote!{
+    impl ::core::str::FromStr for Test {
+        type Err = o2o::traits::ParseEnumError;
+        fn from_str(s: &str) -> ::core::result::Result<Test, o2o::traits::ParseEnumError> {
+            match s {
+                "a" => Ok(Test::A),
+                "B" => Ok(Test::B),
+                _ => Err(o2o::traits::ParseEnumError),
+            }
+        }
+    }
+    impl ::core::fmt::Display for Test {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.write_str(match self {
+                Test::A => "a",
+                Test::B => "B",
+            })
+        }
+    }
+}; "1")]
+#[test_case(quote!{
+    #[o2o(from_str(MyError| case_insensitive, rename_all("snake_case")))]
+    enum Test {
+        SomeValue,
+    }
+},
+quote!{
+    impl ::core::str::FromStr for Test {
+        type Err = MyError;
+        fn from_str(s: &str) -> ::core::result::Result<Test, MyError> {
+            match s {
+                s if s.eq_ignore_ascii_case("some_value") => Ok(Test::SomeValue),
+                _ => Err(::core::convert::From::from(o2o::traits::ParseEnumError)),
+            }
+        }
+    }
+}; "2")]
+fn from_str(code_fragment: TokenStream, expected_output: TokenStream) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+
+    assert!(output.is_ok());
+    assert_eq!(output.unwrap().to_string().trim(), expected_output.to_string().trim());
+}
+
+#[test_case(quote!{
+    #[o2o(from_str)]
+    struct Test {
+        x: i32,
+    }
+}, "String conversions are only applicable to enums."; "1")]
+#[test_case(quote!{
+    #[o2o(display)]
+    enum Test {
+        A(i32),
+    }
+}, "String conversions only support unit variants."; "2")]
+#[test_case(quote!{
+    #[o2o(from_str)]
+    enum Test {
+        #[literal(1)]
+        A,
+    }
+}, "Variant 'A' should have a string #[literal(...)] instruction for string conversions."; "3")]
+#[test_case(quote!{
+    #[o2o(display(MyError))]
+    enum Test {
+        A,
+    }
+}, "Error type is only applicable to 'from_str' instruction."; "4")]
+#[test_case(quote!{
+    #[o2o(display(case_insensitive))]
+    enum Test {
+        A,
+    }
+}, "Case insensitive matching is only applicable to 'from_str' instruction."; "5")]
+#[test_case(quote!{
+    #[o2o(from_str)]
+    #[o2o(from_str(case_insensitive))]
+    enum Test {
+        A,
+    }
+}, "There can be at most one #[o2o(from_str(...))] instruction."; "6")]
+#[test_case(quote!{
+    #[o2o(from_str(case_insensitive, case_insensitive))]
+    enum Test {
+        A,
+    }
+}, "Instruction parameter 'case_insensitive' was already set."; "7")]
+#[test_case(quote!{
+    #[o2o(from_str(MyError| lowercase))]
+    enum Test {
+        A,
+    }
+}, "Expected 'case_insensitive' or 'rename_all(...)' instruction parameter."; "8")]
+fn from_str_errors(code_fragment: TokenStream, err: &str) {
+    let input: DeriveInput = syn::parse2(code_fragment).unwrap();
+    let output = derive(&input);
+    let message = get_error(output, !err.starts_with("Instruction parameter") && !err.starts_with("Expected"));
+
+    assert_eq!(message, err);
+}
+
+// endregion: from_str
 
 // region: lifetimes
 
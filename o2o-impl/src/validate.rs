@@ -1,8 +1,8 @@
 use crate::{
-    ast::{DataType, DataTypeMember, Struct, Variant},
-    attr::{ChildAttr, ChildParentsAttr, DataTypeAttrs, DataTypeInstruction, FallibleKind, GhostsAttr, Kind, MemberAttrs, MemberInstruction, ParentAttr, TraitAttr, TraitAttrCore, TypeHint, TypePath, WhereAttr},
+    ast::{DataType, DataTypeMember, Enum, Field, Struct, Variant},
+    attr::{ApplicableAttr, ChildParentsAttr, DataTypeAttrs, DataTypeInstruction, FallibleKind, GhostsAttr, Kind, MemberAttrs, MemberInstruction, ParentAttr, TraitAttr, TraitAttrCore, TypeHint, TypePath, WhereAttr},
 };
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use std::collections::{HashMap, HashSet};
 
@@ -11,11 +11,11 @@ use syn2 as syn;
 
 use syn::{spanned::Spanned, Result};
 
-pub(crate) fn validate(input: &DataType) -> Result<()> {
+pub(crate) fn validate(input: &DataType, in_mapping: bool) -> Result<()> {
     let attrs = input.get_attrs();
     let mut errors: HashMap<String, Span> = HashMap::new();
 
-    if attrs.attrs.is_empty() {
+    if attrs.attrs.is_empty() && attrs.from_str_attrs.is_empty() && attrs.display_attrs.is_empty() {
         errors.insert("At least one trait instruction is expected.".into(), Span::call_site());
     }
 
@@ -46,6 +46,37 @@ pub(crate) fn validate(input: &DataType) -> Result<()> {
 
     validate_child_parents_attrs(&attrs.child_parents_attrs, &type_paths, &mut errors);
     validate_where_attrs(&attrs.where_attrs, &type_paths, &mut errors);
+    validate_standalone_fns(&attrs.attrs, &mut errors);
+    validate_impl_from(&attrs.attrs, &mut errors);
+    validate_rename_all(input, &attrs.attrs, &mut errors);
+    validate_infer_bounds(input, &attrs.attrs, &mut errors);
+
+    for attr in attrs.attrs.iter().filter(|x| x.core.with.is_some()) {
+        if attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting] {
+            errors.insert("Context arguments are not supported for 'into_existing' instructions.".into(), attr.core.ty.span);
+        }
+    }
+
+    for attr in attrs.attrs.iter().filter(|x| x.core.is_async) {
+        if attr.core.with.is_some() {
+            errors.insert("Context arguments are not supported for async instructions.".into(), attr.core.ty.span);
+        }
+        if attr.core.collect_errors.is_some() || attr.core.error_paths {
+            errors.insert("Async instructions don't support 'collect_errors' and 'error_paths' instruction parameters.".into(), attr.core.ty.span);
+        }
+    }
+
+    for attr in attrs.attrs.iter().filter(|x| !x.fallible && x.core.map_err.is_some()) {
+        errors.insert("Error mapping is only applicable to fallible instructions.".into(), attr.core.ty.span);
+    }
+
+    if let Some(remote_attr) = attrs.remote_attrs.get(1) {
+        errors.insert("There can be at most one #[o2o(remote(...))] instruction.".into(), remote_attr.ty.span);
+    }
+
+    if let Some(remote_attr) = attrs.remote_attrs.first().filter(|_| in_mapping) {
+        errors.insert("Remote types are not supported by 'o2o::mapping!', because its declarations are not emitted. Use #[derive(o2o)] on the shadow type instead.".into(), remote_attr.ty.span);
+    }
 
     let data_type_attrs_by_kind: Vec<(&TraitAttrCore, Kind)> = attrs.iter_for_kind_core(&Kind::OwnedInto, false).map(|x| (x, Kind::OwnedInto))
         .chain(attrs.iter_for_kind_core(&Kind::RefInto, false).map(|x| (x, Kind::RefInto)))
@@ -61,6 +92,8 @@ pub(crate) fn validate(input: &DataType) -> Result<()> {
         .chain(attrs.iter_for_kind_core(&Kind::FromRef, true).map(|x| (x, Kind::FromRef)))
        .collect();
 
+    validate_builder_ghosts(input, &data_type_attrs_by_kind, &mut errors);
+
     for member in input.get_members() {
         let member_span = member.get_span();
         let member_attrs = member.get_attrs();
@@ -78,15 +111,28 @@ pub(crate) fn validate(input: &DataType) -> Result<()> {
                 bark_at_member_attr(&member_attrs.ghosts_attrs.iter().filter(|x| !x.applicable_to[&Kind::OwnedInto] && x.applicable_to[&Kind::RefInto]).collect(), "ghosts_ref", |_| f.member.span(), &mut errors);
 
                 validate_dedicated_member_attrs(&member_attrs.parent_attrs, |x| x.container_ty.as_ref(), Some("parent"), member_span, &type_paths, &mut errors);
+                validate_dedicated_member_attrs(&member_attrs.map_opt_attrs, |x| x.container_ty.as_ref(), Some("map_opt"), member_span, &type_paths, &mut errors);
+                validate_dedicated_member_attrs(&member_attrs.map_items_attrs, |x| x.container_ty.as_ref(), Some("map_items"), member_span, &type_paths, &mut errors);
+
+                if type_paths.iter().any(|x| member_attrs.map_opt(x).is_some() && member_attrs.map_items(x).is_some()) {
+                    errors.insert("Instructions 'map_opt' and 'map_items' can't be applied to the same member.".into(), member_span);
+                }
 
                 validate_parent_attrs(input.named_fields(), &member_attrs.parent_attrs, &data_type_attrs_by_kind, &mut errors);
+                validate_member_map_err(f, attrs, false, &mut errors);
             },
             DataTypeMember::Variant(v) => {
                 bark_at_member_attr(&member_attrs.parent_attrs, "parent", |_| v.ident.span(), &mut errors);
+                bark_at_member_attr(&member_attrs.map_opt_attrs, "map_opt", |_| v.ident.span(), &mut errors);
+                bark_at_member_attr(&member_attrs.map_items_attrs, "map_items", |_| v.ident.span(), &mut errors);
 
                 validate_dedicated_member_attrs(&member_attrs.lit_attrs, |x| x.container_ty.as_ref(), Some("literal"), member_span, &type_paths, &mut errors);
                 validate_dedicated_member_attrs(&member_attrs.pat_attrs, |x| x.container_ty.as_ref(), Some("pattern"), member_span, &type_paths, &mut errors);
                 validate_dedicated_member_attrs(&member_attrs.type_hint_attrs, |x| x.container_ty.as_ref(), Some("type_hint"), member_span, &type_paths, &mut errors);
+
+                for f in &v.fields {
+                    validate_member_map_err(f, attrs, true, &mut errors);
+                }
             },
         }
 
@@ -97,18 +143,39 @@ pub(crate) fn validate(input: &DataType) -> Result<()> {
         DataType::Struct(s) => {
             validate_fields(s, attrs, &data_type_attrs_by_kind, &type_paths, &mut errors);
 
+            for attr in attrs.from_str_attrs.iter().chain(&attrs.display_attrs) {
+                errors.insert("String conversions are only applicable to enums.".into(), attr.span);
+            }
+
+            if attrs.transparent && s.fields.len() != 1 {
+                errors.insert("Transparent mode is only applicable to structs with a single field.".into(), s.ident.span());
+            }
+
             for attr in &attrs.attrs {
                 check_misplaced_instrs_struct(&attr.core, &mut errors);
+                validate_shape_check(s, &attr.core, &mut errors);
+                validate_collect_errors(s, attr, &mut errors);
+                validate_error_paths(attr, &mut errors);
+                validate_diff(attr, &mut errors);
+                validate_patch(attr, &mut errors);
+                validate_default_init(attr, &mut errors);
             }
 
         },
         DataType::Enum(e) => {
+            if attrs.transparent {
+                errors.insert("Transparent mode is only applicable to structs.".into(), e.ident.span());
+            }
+
             for v in &e.variants {
                 validate_variant_fields(v, attrs, &type_paths, &mut errors);
             }
 
+            validate_str_attrs(e, attrs, &mut errors);
+
             for attr in &attrs.attrs {
                 check_misplaced_instrs_enum(&attr.core, &mut errors);
+                validate_tag(e, attr, &mut errors);
             }
         },
     }
@@ -160,7 +227,7 @@ fn validate_member_error_instrs(input: &DataType, attrs: &MemberAttrs, errors: &
 fn validate_struct_attrs<'a, I: Iterator<Item = &'a TraitAttrCore>>(attrs: I, fallible: bool, errors: &mut HashMap<String, Span>) {
     let mut unique_ident = HashSet::new();
     for attr in attrs {
-        if !unique_ident.insert(&attr.ty) {
+        if attr.standalone_fn.is_none() && !unique_ident.insert((&attr.ty, attr.is_async)) {
             errors.insert("Ident here must be unique.".into(), attr.ty.span);
         }
 
@@ -174,6 +241,19 @@ fn validate_struct_attrs<'a, I: Iterator<Item = &'a TraitAttrCore>>(attrs: I, fa
     }
 }
 
+fn validate_standalone_fns(attrs: &[TraitAttr], errors: &mut HashMap<String, Span>) {
+    let mut unique_ident = HashSet::new();
+    for (attr, standalone_fn) in attrs.iter().filter_map(|x| x.core.standalone_fn.as_ref().map(|f| (x, f))) {
+        if attr.applicable_to.iter().filter(|x| **x).count() > 1 {
+            errors.insert("Standalone functions are only supported for instructions that produce a single conversion (e.g. 'from_owned', 'ref_into', 'owned_into_existing').".into(), standalone_fn.ident.span());
+        }
+
+        if !unique_ident.insert(&standalone_fn.ident) {
+            errors.insert("Function name here must be unique.".into(), standalone_fn.ident.span());
+        }
+    }
+}
+
 fn validate_ghost_attrs(kind: &Kind, ghost_attrs: &[GhostsAttr], type_paths: &HashSet<&TypePath>, errors: &mut HashMap<String, Span>) {
     if ghost_attrs.iter().filter(|x| x.applicable_to[kind] && x.attr.container_ty.is_none()).count() > 1 {
         errors.insert("There can be at most one default #[ghosts(...)] instruction.".into(), Span::call_site());
@@ -192,6 +272,54 @@ fn validate_ghost_attrs(kind: &Kind, ghost_attrs: &[GhostsAttr], type_paths: &Ha
     }
 }
 
+fn validate_builder_ghosts(input: &DataType, attrs_by_kind: &[(&TraitAttrCore, Kind)], errors: &mut HashMap<String, Span>) {
+    for (attr, kind) in attrs_by_kind {
+        let Some(ghosts_attr) = input.get_attrs().ghosts_attr(&attr.ty, kind) else { continue };
+
+        for ghost in ghosts_attr.ghost_data.iter().filter(|x| x.required_ty.is_some()) {
+            let member = ghost.ghost_ident.get_ident();
+            if !matches!(kind, Kind::OwnedInto) {
+                errors.insert("Ghost fields without default value are only supported by 'owned_into' instructions.".into(), member.span());
+            }
+            if let DataType::Enum(_) = input {
+                errors.insert("Ghost fields without default value are only supported for structs.".into(), member.span());
+            }
+            if !input.get_generics().params.is_empty() {
+                errors.insert("Ghost fields without default value are not supported for generic types.".into(), member.span());
+            }
+            if let syn::Member::Unnamed(_) = member {
+                errors.insert("Ghost fields without default value must be named.".into(), member.span());
+            }
+            // 'build' is the method that finishes the builder, 'value' is the field that holds the source
+            if ["build", "value"].iter().any(|x| member.to_token_stream().to_string() == *x) {
+                errors.insert(format!("Ghost field without default value can't be named '{}', because the builder uses that name.", member.to_token_stream()), member.span());
+            }
+        }
+    }
+
+    let mut fn_idents = HashSet::new();
+    for attr in input.get_attrs().attrs.iter().filter(|x| x.applicable_to[&Kind::OwnedInto]) {
+        let Some(ghosts_attr) = input.get_attrs().ghosts_attr(&attr.core.ty, &Kind::OwnedInto) else { continue };
+        let ghosts: Vec<_> = ghosts_attr.ghost_data.iter().filter(|x| x.required_ty.is_some()).collect();
+        if ghosts.is_empty() {
+            continue;
+        }
+
+        let fn_ident = attr.core.builder_fn_ident(attr.fallible);
+        if !fn_idents.insert(fn_ident.to_string()) {
+            errors.insert(format!("Builder method '{}' would be generated more than once. Use fn(...) to give one of the builders another name.", fn_ident), attr.core.ty.span);
+        }
+
+        let mut names = HashSet::new();
+        for ghost in ghosts {
+            let member = ghost.ghost_ident.get_ident();
+            if !names.insert(member.to_token_stream().to_string()) {
+                errors.insert(format!("Ghost fields without default value become builder methods, so their names must be unique. '{}' is used more than once.", member.to_token_stream()), member.span());
+            }
+        }
+    }
+}
+
 fn validate_child_parents_attrs(children_attrs: &[ChildParentsAttr], type_paths: &HashSet<&TypePath>, errors: &mut HashMap<String, Span>) {
     if children_attrs.iter().filter(|x| x.container_ty.is_none()).count() > 1 {
         errors.insert("There can be at most one default #[child_parents(...)] instruction.".into(), Span::call_site());
@@ -267,6 +395,26 @@ fn validate_dedicated_member_attrs<T, U: Fn(&T) -> Option<&TypePath>>(attrs: &Ve
     }
 }
 
+fn validate_member_map_err(f: &Field, attrs: &DataTypeAttrs, variant_field: bool, errors: &mut HashMap<String, Span>) {
+    for kind in [Kind::OwnedInto, Kind::RefInto, Kind::FromOwned, Kind::FromRef, Kind::OwnedIntoExisting, Kind::RefIntoExisting] {
+        for fallible in [false, true] {
+            for attr in attrs.iter_for_kind_core(&kind, fallible) {
+                let Some(ApplicableAttr::Field(member_attr)) = f.attrs.applicable_attr(&kind, fallible, &attr.ty) else { continue };
+                if member_attr.map_err.is_none() {
+                    continue;
+                }
+                // Without an action, only these produce a value that ends with '?'
+                let converts = member_attr.nested || f.attrs.map_opt(&attr.ty).is_some() || f.attrs.map_items(&attr.ty).is_some() || (variant_field && attr.convert_fields);
+                if !fallible {
+                    errors.insert(format!("Member instruction parameter 'map_err' is not applicable to infallible instruction #[{}({}...)].", FallibleKind(kind, false), attr.ty.path_str), f.member.span());
+                } else if member_attr.action.is_none() && !converts {
+                    errors.insert("Member instruction parameter 'map_err' needs an action that ends with '?' or evaluates to a Result.".into(), f.member.span());
+                }
+            }
+        }
+    }
+}
+
 fn validate_parent_attrs(named_root_struct: bool, parent_attrs: &[ParentAttr], data_type_attrs_by_kind: &[(&TraitAttrCore, Kind)], errors: &mut HashMap<String, Span>) {
     for p in parent_attrs {
         for (attr, _) in data_type_attrs_by_kind.iter().filter(|(x, kind)| !kind.is_from() && (p.container_ty.is_none() || &x.ty == p.container_ty.as_ref().unwrap())) {
@@ -291,7 +439,8 @@ fn validate_parent_attrs(named_root_struct: bool, parent_attrs: &[ParentAttr], d
 }
 
 fn validate_fields(input: &Struct, data_type_attrs: &DataTypeAttrs, data_type_attrs_by_kind: &[(&TraitAttrCore, Kind)], type_paths: &HashSet<&TypePath>, errors: &mut HashMap<String, Span>) {
-    let into_type_paths = data_type_attrs_by_kind.iter().filter_map(|(x, kind)|(!kind.is_from() && !kind.is_into_existing()).then_some(&x.ty)).collect::<HashSet<_>>();
+    // Targets built with Default::default() get child fields assigned through their paths, so types of the intermediate structs are not needed
+    let into_type_paths = data_type_attrs_by_kind.iter().filter_map(|(x, kind)|(!kind.is_from() && !kind.is_into_existing() && !x.default_init).then_some(&x.ty)).collect::<HashSet<_>>();
     let from_type_paths = data_type_attrs_by_kind.iter().filter_map(|(x, kind)|(x.update.is_none() && kind.is_from()).then_some(&x.ty)).collect::<HashSet<_>>();
 
     for field in &input.fields {
@@ -314,6 +463,12 @@ fn validate_fields(input: &Struct, data_type_attrs: &DataTypeAttrs, data_type_at
             }
         }
 
+        for member_attr in field.attrs.attrs.iter().filter(|x| x.attr.skip_if.is_some()) {
+            if member_attr.applicable_to[&Kind::OwnedInto] || member_attr.applicable_to[&Kind::RefInto] || member_attr.applicable_to[&Kind::FromOwned] || member_attr.applicable_to[&Kind::FromRef] {
+                errors.insert("Skip conditions are only applicable to 'into_existing' member instructions.".into(), field.member.span());
+            }
+        }
+
         if let Some(repeat_attr) = &field.attrs.repeat {
             if repeat_attr.permeate {
                 errors.insert("Permeating repeat instruction is only applicable to enum variant fields.".into(), field.member.span());
@@ -322,21 +477,17 @@ fn validate_fields(input: &Struct, data_type_attrs: &DataTypeAttrs, data_type_at
     }
 
     for child_attr in input.fields.iter().flat_map(|x| &x.attrs.child_attrs) {
-        match &child_attr.container_ty {
-            Some(tp) => {
-                if !type_paths.contains(tp) {
-                    errors.insert(format!("Type '{}' doesn't match any type specified in trait instructions.", tp.path_str), tp.span);
-                }
-                if into_type_paths.contains(tp) {
-                    check_child_errors(child_attr, data_type_attrs, tp, errors)
-                }
-            },
-            None => for tp in into_type_paths.iter() {
-                check_child_errors(child_attr, data_type_attrs, tp, errors)
-            },
+        if let Some(tp) = &child_attr.container_ty {
+            if !type_paths.contains(tp) {
+                errors.insert(format!("Type '{}' doesn't match any type specified in trait instructions.", tp.path_str), tp.span);
+            }
         }
     }
 
+    for tp in into_type_paths.iter() {
+        check_child_errors(input, data_type_attrs, tp, errors)
+    }
+
     if !input.named_fields {
         for (data_type_attr, kind) in data_type_attrs_by_kind {
             if data_type_attr.quick_return.is_none() && data_type_attr.type_hint == TypeHint::Struct {
@@ -402,21 +553,39 @@ fn validate_variant_fields(input: &Variant, data_type_attrs: &DataTypeAttrs, _ty
     }
 }
 
-fn check_child_errors(child_attr: &ChildAttr, struct_attrs: &DataTypeAttrs, tp: &TypePath, errors: &mut HashMap<String, Span>) {
+fn check_child_errors(input: &Struct, struct_attrs: &DataTypeAttrs, tp: &TypePath, errors: &mut HashMap<String, Span>) {
+    // Parameterless #[parent] instructions build the target with Default::default() too.
+    if input.fields.iter().any(|x| x.attrs.has_parameterless_parent_attr(tp)) {
+        return;
+    }
+
     let children_attr = struct_attrs.child_parents_attr(tp);
-    for (idx, _level) in child_attr.child_path.child_path.iter().enumerate() {
-        let path = child_attr.get_child_path_str(Some(idx));
-        match children_attr {
-            Some(children_attr) => {
-                if !children_attr.child_parents.iter().any(|x| x.check_match(path)) {
-                    errors.insert(format!("Missing '{}: [Type Path]' instruction for type {}", path, tp.path_str), tp.span);
-                }
-            },
-            None => {
-                errors.insert(format!("Missing #[child_parents(...)] instruction for {}", tp.path_str), tp.span);
-            },
+    let mut missing: Vec<(&str, &Field)> = vec![];
+
+    for field in &input.fields {
+        let Some(child_attr) = field.attrs.child(tp) else { continue };
+        for idx in 0..child_attr.child_path.child_path_str.len() {
+            let path = child_attr.get_child_path_str(Some(idx));
+            let declared = children_attr.is_some_and(|x| x.child_parents.iter().any(|x| x.check_match(path)));
+            if !declared && !missing.iter().any(|(p, _)| *p == path) {
+                missing.push((path, field));
+            }
         }
     }
+
+    if missing.is_empty() {
+        return;
+    }
+
+    match children_attr {
+        Some(_) => for (path, field) in missing {
+            errors.insert(format!("Missing '{}: [Type Path]' instruction for type {}, required by member '{}'", path, tp.path_str, field.member.to_token_stream()), field.member.span());
+        },
+        None => {
+            let paths = missing.iter().map(|(p, _)| format!("'{}'", p)).collect::<Vec<_>>().join(", ");
+            errors.insert(format!("Missing #[child_parents(...)] instruction for {}, which should specify types for {}", tp.path_str, paths), tp.span);
+        },
+    }
 }
 
 fn check_misplaced_instrs_struct(attr: &TraitAttrCore, errors: &mut HashMap<String, Span>) {
@@ -426,10 +595,252 @@ fn check_misplaced_instrs_struct(attr: &TraitAttrCore, errors: &mut HashMap<Stri
     if let Some(match_expr) = &attr.match_expr {
         errors.insert(format!("Match instructions are only applicable to enums."), match_expr.span);
     }
+    if attr.tag.is_some() {
+        errors.insert("Tag instructions are only applicable to enums.".into(), attr.ty.span);
+    }
+    if attr.convert_fields {
+        errors.insert("Field conversion is only applicable to enums.".into(), attr.ty.span);
+    }
+}
+
+fn validate_shape_check(input: &Struct, attr: &TraitAttrCore, errors: &mut HashMap<String, Span>) {
+    if !attr.check_shape {
+        return;
+    }
+    if !input.generics.params.is_empty() {
+        errors.insert("Shape checks are not supported for generic structs.".into(), attr.ty.span);
+    }
+    if attr.type_hint != TypeHint::Unspecified {
+        errors.insert("Shape checks are not supported together with type hints.".into(), attr.ty.span);
+    }
+}
+
+fn validate_error_paths(attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if !attr.core.error_paths {
+        return;
+    }
+    if !attr.fallible {
+        errors.insert("Error paths are only applicable to fallible instructions.".into(), attr.core.ty.span);
+    }
+    if attr.core.collect_errors.is_some() {
+        errors.insert("Error paths are not supported together with error collection.".into(), attr.core.ty.span);
+    }
+}
+
+fn validate_diff(attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if !attr.core.diff {
+        return;
+    }
+    if attr.fallible || !(attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting]) {
+        errors.insert("Diff is only applicable to infallible 'into_existing' instructions.".into(), attr.core.ty.span);
+    }
+    if attr.core.quick_return.is_some() {
+        errors.insert("Diff is not supported together with quick returns.".into(), attr.core.ty.span);
+    }
+}
+
+fn validate_impl_from(attrs: &[TraitAttr], errors: &mut HashMap<String, Span>) {
+    for attr in attrs.iter().filter(|x| x.core.impl_from) {
+        if !(attr.applicable_to[&Kind::OwnedInto] || attr.applicable_to[&Kind::RefInto]) || attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting] || attr.applicable_to[&Kind::FromOwned] || attr.applicable_to[&Kind::FromRef] {
+            errors.insert("From implementations are only applicable to 'into' instructions.".into(), attr.core.ty.span);
+        }
+        if attr.core.with.is_some() || attr.core.standalone_fn.is_some() || attr.core.is_async {
+            errors.insert("From implementations are not supported together with context arguments, standalone functions and async instructions.".into(), attr.core.ty.span);
+        }
+    }
+}
+
+fn validate_patch(attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if attr.core.patch && !(attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting]) {
+        errors.insert("Patch mode is only applicable to 'into_existing' instructions.".into(), attr.core.ty.span);
+    }
+}
+
+fn validate_default_init(attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if attr.core.default_init && !(attr.applicable_to[&Kind::OwnedInto] || attr.applicable_to[&Kind::RefInto]) {
+        errors.insert("Default initialization is only applicable to 'into' instructions.".into(), attr.core.ty.span);
+    }
+}
+
+fn validate_tag(input: &Enum, attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if attr.core.tag.is_none() {
+        return;
+    }
+    if attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting] {
+        errors.insert("Tag instructions are not supported for 'into_existing' instructions.".into(), attr.core.ty.span);
+        return;
+    }
+    if attr.applicable_to[&Kind::FromOwned] || attr.applicable_to[&Kind::FromRef] {
+        if !attr.fallible {
+            errors.insert("Tagged conversions from structs are only supported by fallible instructions (e.g. 'try_from').".into(), attr.core.ty.span);
+        }
+        if attr.core.default_case.is_none() {
+            errors.insert("Tagged conversions from structs require a default case (e.g. '_ => Err(...)?').".into(), attr.core.ty.span);
+        }
+    }
+
+    let ty = &attr.core.ty;
+    for kind in [Kind::FromOwned, Kind::FromRef, Kind::OwnedInto, Kind::RefInto].iter().filter(|x| attr.applicable_to[*x]) {
+        for v in input.variants.iter().filter(|x| x.attrs.applicable_attr(kind, attr.fallible, ty).is_none()) {
+            if !v.named_fields && !v.unit {
+                errors.insert("Tagged conversions only support unit variants and variants with named fields.".into(), v.ident.span());
+            }
+            if v.attrs.lit(ty).is_none() && (!kind.is_from() || v.attrs.pat(ty).is_none()) {
+                errors.insert(format!("Variant '{}' should have #[literal(...)] instruction for tagged conversion with {}", v.ident, ty.path_str), v.ident.span());
+            }
+        }
+    }
+}
+
+fn validate_infer_bounds(input: &DataType, attrs: &[TraitAttr], errors: &mut HashMap<String, Span>) {
+    let these: Vec<&syn::Ident> = input.get_generics().type_params().map(|x| &x.ident).collect();
+
+    for attr in attrs {
+        let Some(params) = &attr.core.infer_bounds else { continue };
+        let those: Vec<&syn::Type> = attr.core.ty.generics.as_ref().map(|g| g.args.iter().filter_map(|g| match g {
+            syn::GenericArgument::Type(t) => Some(t),
+            _ => None
+        }).collect()).unwrap_or_default();
+
+        if these.len() != those.len() {
+            errors.insert(format!("Bounds can't be inferred, because the instruction type has {} generic type arguments, while the data type has {} type parameters.", those.len(), these.len()), attr.core.ty.span);
+        }
+        for param in params {
+            if these.contains(&param) {
+                errors.insert(format!("'{}' is already a type parameter of the data type.", param), param.span());
+            } else if !those.iter().any(|x| matches!(x, syn::Type::Path(p) if p.path.is_ident(param))) {
+                errors.insert(format!("'{}' is not a generic argument of the instruction type.", param), param.span());
+            }
+        }
+    }
+}
+
+fn validate_rename_all(input: &DataType, attrs: &[TraitAttr], errors: &mut HashMap<String, Span>) {
+    let fields: Vec<&Field> = match input {
+        DataType::Struct(s) => s.fields.iter().collect(),
+        DataType::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+    };
+
+    for attr in attrs {
+        let Some(rule) = &attr.core.rename_all else { continue };
+        for f in &fields {
+            let syn::Member::Named(ident) = &f.member else { continue };
+            if f.attrs.attrs.iter().any(|x| x.attr.member.is_some() && (x.attr.container_ty.is_none() || x.attr.container_ty.as_ref() == Some(&attr.core.ty))) {
+                continue;
+            }
+            let name = ident.to_string();
+            let name = name.trim_start_matches("r#");
+            let renamed = rule.apply(name);
+            if renamed != name && syn::parse_str::<syn::Ident>(&renamed).is_err() {
+                errors.insert(format!("Rename rule turns '{}' into '{}', which is not a valid identifier.", name, renamed), ident.span());
+            }
+        }
+    }
+}
+
+fn validate_str_attrs(input: &Enum, attrs: &DataTypeAttrs, errors: &mut HashMap<String, Span>) {
+    if let Some(attr) = attrs.from_str_attrs.get(1) {
+        errors.insert("There can be at most one #[o2o(from_str(...))] instruction.".into(), attr.span);
+    }
+    if let Some(attr) = attrs.display_attrs.get(1) {
+        errors.insert("There can be at most one #[o2o(display(...))] instruction.".into(), attr.span);
+    }
+    for attr in &attrs.display_attrs {
+        if let Some(err_ty) = &attr.err_ty {
+            errors.insert("Error type is only applicable to 'from_str' instruction.".into(), err_ty.span);
+        }
+        if attr.case_insensitive {
+            errors.insert("Case insensitive matching is only applicable to 'from_str' instruction.".into(), attr.span);
+        }
+    }
+
+    if attrs.from_str_attrs.is_empty() && attrs.display_attrs.is_empty() {
+        return;
+    }
+
+    for v in &input.variants {
+        if !v.unit {
+            errors.insert("String conversions only support unit variants.".into(), v.ident.span());
+        }
+        if let Some(lit) = v.attrs.str_lit() {
+            if syn::parse2::<syn::LitStr>(lit.tokens.clone()).is_err() {
+                errors.insert(format!("Variant '{}' should have a string #[literal(...)] instruction for string conversions.", v.ident), lit.tokens.span());
+            }
+        }
+    }
+}
+
+fn validate_collect_errors(input: &Struct, attr: &TraitAttr, errors: &mut HashMap<String, Span>) {
+    if attr.core.collect_errors.is_none() {
+        return;
+    }
+    if !attr.fallible {
+        errors.insert("Error collection is only applicable to fallible instructions.".into(), attr.core.ty.span);
+    }
+    if attr.applicable_to[&Kind::OwnedIntoExisting] || attr.applicable_to[&Kind::RefIntoExisting] {
+        errors.insert("Error collection is not supported for 'into_existing' instructions.".into(), attr.core.ty.span);
+    }
+    let ty = &attr.core.ty;
+    if input.fields.iter().any(|f| f.attrs.child(ty).is_some() || f.attrs.has_parent_attr(ty)) || input.attrs.ghosts_attrs.iter().flat_map(|x| &x.attr.ghost_data).any(|x| x.child_path.is_some()) {
+        errors.insert("Error collection is not supported together with child and parent instructions.".into(), attr.core.ty.span);
+    }
+    // Errors are collected from the trailing '?' only, any other '?' would return from the function
+    let kinds = [Kind::OwnedInto, Kind::RefInto, Kind::FromOwned, Kind::FromRef];
+    for f in &input.fields {
+        for kind in kinds.iter().filter(|x| attr.applicable_to[*x]) {
+            let action = match f.attrs.applicable_attr(kind, attr.fallible, ty) {
+                Some(ApplicableAttr::Field(member_attr)) => member_attr.action.as_ref(),
+                Some(ApplicableAttr::Ghost(ghost_attr)) => ghost_attr.action.as_ref(),
+                _ => None,
+            };
+            if action.is_some_and(has_inner_question_mark) {
+                errors.insert("Error collection only supports '?' at the end of member actions.".into(), f.member.span());
+            }
+        }
+    }
+    for kind in [Kind::OwnedInto, Kind::RefInto].iter().filter(|x| attr.applicable_to[*x]) {
+        if input.attrs.ghosts_attr(ty, kind).into_iter().flat_map(|x| &x.ghost_data).any(|x| has_inner_question_mark(&x.action)) {
+            errors.insert("Error collection only supports '?' at the end of ghost actions.".into(), attr.core.ty.span);
+        }
+    }
+}
+
+fn has_inner_question_mark(tokens: &TokenStream) -> bool {
+    fn contains(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|x| match x {
+            TokenTree::Punct(p) => p.as_char() == '?',
+            TokenTree::Group(g) => contains(g.stream()),
+            _ => false,
+        })
+    }
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    let inner = match tokens.last() {
+        Some(TokenTree::Punct(p)) if p.as_char() == '?' => &tokens[..tokens.len() - 1],
+        _ => &tokens[..],
+    };
+    contains(inner.iter().cloned().collect())
 }
 
 fn check_misplaced_instrs_enum(attr: &TraitAttrCore, errors: &mut HashMap<String, Span>) {
     if let Some(update) = &attr.update {
         errors.insert(format!("Update instructions are only applicable to structs."), update.span);
     }
+    if attr.check_shape {
+        errors.insert("Shape checks are only applicable to structs.".into(), attr.ty.span);
+    }
+    if attr.collect_errors.is_some() {
+        errors.insert("Error collection is only applicable to structs.".into(), attr.ty.span);
+    }
+    if attr.error_paths {
+        errors.insert("Error paths are only applicable to structs.".into(), attr.ty.span);
+    }
+    if attr.diff {
+        errors.insert("Diff is only applicable to structs.".into(), attr.ty.span);
+    }
+    if attr.patch {
+        errors.insert("Patch mode is only applicable to structs.".into(), attr.ty.span);
+    }
+    if attr.default_init {
+        errors.insert("Default initialization is only applicable to structs.".into(), attr.ty.span);
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 extern crate o2o_impl;
 
-use o2o_impl::expand::derive;
+use o2o_impl::expand::{derive, derive_shape, expand_convert, expand_mapping};
 use proc_macro::TokenStream;
 
 #[cfg(feature = "syn2")]
@@ -105,6 +105,12 @@ use syn::{parse_macro_input, DeriveInput};
         owned_try_into_existing,
         ref_try_into_existing,
         try_into_existing,
+        async_try_from_owned,
+        async_try_from_ref,
+        async_try_from,
+        async_owned_try_into,
+        async_ref_try_into,
+        async_try_into,
         child,
         children,
         child_parents,
@@ -124,3 +130,31 @@ pub fn derive_o2o(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive(&input).unwrap_or_else(|err| err.to_compile_error()).into()
 }
+
+/// Implements `o2o::traits::Shape` for a struct, which lists its field names.
+///
+/// Used by o2o to verify at compile time that mapped fields exist in this struct
+/// (see `check_shape` trait instruction parameter).
+#[proc_macro_derive(Shape)]
+pub fn derive_o2o_shape(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_shape(&input).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+/// Implements conversions for types, that can't be annotated with `#[derive(o2o)]` (e.g. foreign or generated types).
+///
+/// Accepts one or more struct or enum declarations with the same instructions as `#[derive(o2o)]`.
+/// Declarations only describe the types, so they are not emitted, and only the conversions are.
+#[proc_macro]
+pub fn mapping(input: TokenStream) -> TokenStream {
+    expand_mapping(input.into()).unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+/// Converts a value into a struct inline, without declaring any conversion traits.
+///
+/// `convert!(value => Target { a, b: ~.clone(), c: @.x + 1 })` uses the same `~` and `@` substitution and field shorthand as member instructions.
+/// `=>?` wraps the result in `Ok(...)`, so that field expressions can use `?`, which returns from the enclosing function.
+#[proc_macro]
+pub fn convert(input: TokenStream) -> TokenStream {
+    expand_convert(input.into()).unwrap_or_else(|err| err.to_compile_error()).into()
+}
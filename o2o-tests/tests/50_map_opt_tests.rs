@@ -0,0 +1,219 @@
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Clone, Default)]
+struct Child {
+    child_int: i32,
+}
+
+#[derive(Clone, Default)]
+struct Entity {
+    some_int: i32,
+    child: Option<Child>,
+    another_child: Option<Child>,
+}
+
+#[derive(Default)]
+struct TupleEntity(i32, Option<Child>);
+
+#[derive(o2o)]
+#[map(Child)]
+struct ChildDto {
+    #[map(child_int)]
+    int: i32,
+}
+
+#[derive(o2o)]
+#[map(Entity)]
+#[into_existing(Entity)]
+struct EntityDto {
+    some_int: i32,
+    #[o2o(map_opt)]
+    child: Option<ChildDto>,
+    #[o2o(map(another_child), map_opt)]
+    kid: Option<ChildDto>,
+}
+
+#[derive(o2o)]
+#[map(TupleEntity)]
+#[into_existing(TupleEntity)]
+struct TupleEntityDto(i32, #[o2o(map_opt)] Option<ChildDto>);
+
+struct EntityView {
+    some_int: i32,
+    child: Option<ChildDto>,
+}
+
+#[derive(o2o)]
+#[map(Entity)]
+#[map_owned(EntityView)]
+struct EntityModel {
+    some_int: i32,
+    #[o2o(map_opt(Entity))]
+    child: Option<ChildDto>,
+    #[ghost(EntityView| None)]
+    #[o2o(map_opt(Entity))]
+    another_child: Option<ChildDto>,
+}
+
+#[derive(Clone)]
+enum Enum {
+    Var1(i32, Option<Child>),
+    Var2 { child: Option<Child> },
+}
+
+#[derive(o2o)]
+#[map(Enum)]
+enum EnumDto {
+    Var1(#[map_ref(*~)] i32, #[o2o(map_opt)] Option<ChildDto>),
+    Var2 {
+        #[o2o(map_opt)]
+        child: Option<ChildDto>,
+    },
+}
+
+#[test]
+fn named2named() {
+    let dto = EntityDto { some_int: 123, child: Some(ChildDto { int: 321 }), kid: None };
+
+    let entity: Entity = dto.into();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.child.unwrap().child_int);
+    assert!(entity.another_child.is_none());
+}
+
+#[test]
+fn named2named_reverse() {
+    let entity = Entity { some_int: 123, child: None, another_child: Some(Child { child_int: 321 }) };
+
+    let dto: EntityDto = entity.into();
+
+    assert_eq!(123, dto.some_int);
+    assert!(dto.child.is_none());
+    assert_eq!(321, dto.kid.unwrap().int);
+}
+
+#[test]
+fn named2named_ref() {
+    let dto = &EntityDto { some_int: 123, child: Some(ChildDto { int: 321 }), kid: None };
+
+    let entity: Entity = dto.into();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.child.unwrap().child_int);
+    assert!(entity.another_child.is_none());
+}
+
+#[test]
+fn named2named_ref_reverse() {
+    let entity = &Entity { some_int: 123, child: None, another_child: Some(Child { child_int: 321 }) };
+
+    let dto: EntityDto = entity.into();
+
+    assert_eq!(123, dto.some_int);
+    assert!(dto.child.is_none());
+    assert_eq!(321, dto.kid.unwrap().int);
+}
+
+#[test]
+fn existing_named2named() {
+    let dto = EntityDto { some_int: 123, child: Some(ChildDto { int: 321 }), kid: None };
+
+    let mut entity = Entity { some_int: 0, child: None, another_child: Some(Child { child_int: 456 }) };
+    dto.into_existing(&mut entity);
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.child.unwrap().child_int);
+    assert!(entity.another_child.is_none());
+}
+
+#[test]
+fn existing_named2named_ref() {
+    let dto = &EntityDto { some_int: 123, child: Some(ChildDto { int: 321 }), kid: None };
+
+    let mut entity = Entity::default();
+    dto.into_existing(&mut entity);
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.child.unwrap().child_int);
+    assert!(entity.another_child.is_none());
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let dto = TupleEntityDto(123, Some(ChildDto { int: 321 }));
+
+    let entity: TupleEntity = dto.into();
+
+    assert_eq!(123, entity.0);
+    assert_eq!(321, entity.1.as_ref().unwrap().child_int);
+
+    let dto: TupleEntityDto = (&entity).into();
+
+    assert_eq!(123, dto.0);
+    assert_eq!(321, dto.1.unwrap().int);
+
+    let dto: TupleEntityDto = TupleEntity(123, None).into();
+
+    assert_eq!(123, dto.0);
+    assert!(dto.1.is_none());
+}
+
+#[test]
+fn existing_unnamed2unnamed() {
+    let dto = TupleEntityDto(123, None);
+
+    let mut entity = TupleEntity(0, Some(Child { child_int: 321 }));
+    (&dto).into_existing(&mut entity);
+
+    assert_eq!(123, entity.0);
+    assert!(entity.1.is_none());
+}
+
+#[test]
+fn dedicated_map_opt() {
+    let entity = Entity { some_int: 123, child: Some(Child { child_int: 321 }), another_child: Some(Child { child_int: 456 }) };
+
+    let model: EntityModel = (&entity).into();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.child.as_ref().unwrap().int);
+    assert_eq!(456, model.another_child.as_ref().unwrap().int);
+
+    let view: EntityView = model.into();
+
+    assert_eq!(123, view.some_int);
+    assert_eq!(321, view.child.as_ref().unwrap().int);
+
+    let model: EntityModel = view.into();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.child.as_ref().unwrap().int);
+    assert!(model.another_child.is_none());
+}
+
+#[test]
+fn enum2enum() {
+    let en = Enum::Var1(123, Some(Child { child_int: 321 }));
+
+    let dto: EnumDto = en.clone().into();
+    let EnumDto::Var1(int, child) = dto else { panic!() };
+    assert_eq!(123, int);
+    assert_eq!(321, child.unwrap().int);
+
+    let dto: EnumDto = (&en).into();
+    let en: Enum = dto.into();
+    let Enum::Var1(int, child) = en else { panic!() };
+    assert_eq!(123, int);
+    assert_eq!(321, child.unwrap().child_int);
+
+    let dto = EnumDto::Var2 { child: Some(ChildDto { int: 321 }) };
+    let en: Enum = (&dto).into();
+    let Enum::Var2 { child } = en else { panic!() };
+    assert_eq!(321, child.unwrap().child_int);
+
+    let dto: EnumDto = Enum::Var2 { child: None }.into();
+    let EnumDto::Var2 { child } = dto else { panic!() };
+    assert!(child.is_none());
+}
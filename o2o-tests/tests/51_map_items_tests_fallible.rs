@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use o2o::o2o;
+use o2o::traits::TryIntoExisting;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Child {
+    child_int: i32,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Key(i32);
+
+#[derive(Default)]
+struct Entity {
+    children: Vec<Child>,
+    set: HashSet<Child>,
+    map: HashMap<String, Child>,
+    tree: BTreeMap<Key, Child>,
+    keys: BTreeMap<Key, i32>,
+    array: [Child; 2],
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, o2o)]
+#[try_map(Child, String)]
+struct ChildDto {
+    #[from(child_int, if ~ < 0 { Err("negative")? } else { ~ })]
+    child_int: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, o2o)]
+#[try_map(Key, String)]
+struct KeyDto(i32);
+
+#[derive(o2o)]
+#[try_map(Entity, String)]
+#[try_into_existing(Entity, String)]
+struct EntityDto {
+    #[o2o(map_items)]
+    children: Vec<ChildDto>,
+    #[o2o(map_items)]
+    set: HashSet<ChildDto>,
+    #[o2o(map_items(values))]
+    map: HashMap<String, ChildDto>,
+    #[o2o(map_items(keys, values))]
+    tree: BTreeMap<KeyDto, ChildDto>,
+    #[o2o(map_items(keys))]
+    keys: BTreeMap<KeyDto, i32>,
+    #[o2o(map_items)]
+    array: [ChildDto; 2],
+}
+
+#[derive(Default)]
+struct TupleEntity(i32, Vec<Child>);
+
+#[derive(o2o)]
+#[try_map(TupleEntity, String)]
+#[try_into_existing(TupleEntity, String)]
+struct TupleEntityDto(i32, #[o2o(map_items)] Vec<ChildDto>);
+
+enum Enum {
+    Var1(Vec<Child>),
+    Var2 { map: HashMap<String, Child> },
+}
+
+#[derive(o2o)]
+#[try_map(Enum, String)]
+enum EnumDto {
+    Var1(#[o2o(map_items)] Vec<ChildDto>),
+    Var2 {
+        #[o2o(map_items(values))]
+        map: HashMap<String, ChildDto>,
+    },
+}
+
+fn entity() -> Entity {
+    Entity {
+        children: vec![Child { child_int: 1 }, Child { child_int: 2 }],
+        set: HashSet::from([Child { child_int: 3 }]),
+        map: HashMap::from([("a".into(), Child { child_int: 4 })]),
+        tree: BTreeMap::from([(Key(5), Child { child_int: 6 })]),
+        keys: BTreeMap::from([(Key(7), 8)]),
+        array: [Child { child_int: 9 }, Child { child_int: 10 }],
+    }
+}
+
+fn dto() -> EntityDto {
+    EntityDto {
+        children: vec![ChildDto { child_int: 1 }, ChildDto { child_int: 2 }],
+        set: HashSet::from([ChildDto { child_int: 3 }]),
+        map: HashMap::from([("a".into(), ChildDto { child_int: 4 })]),
+        tree: BTreeMap::from([(KeyDto(5), ChildDto { child_int: 6 })]),
+        keys: BTreeMap::from([(KeyDto(7), 8)]),
+        array: [ChildDto { child_int: 9 }, ChildDto { child_int: 10 }],
+    }
+}
+
+fn assert_entity(entity: &Entity) {
+    assert!(entity.children == vec![Child { child_int: 1 }, Child { child_int: 2 }]);
+    assert!(entity.set == HashSet::from([Child { child_int: 3 }]));
+    assert!(entity.map == HashMap::from([("a".into(), Child { child_int: 4 })]));
+    assert!(entity.tree == BTreeMap::from([(Key(5), Child { child_int: 6 })]));
+    assert!(entity.keys == BTreeMap::from([(Key(7), 8)]));
+    assert!(entity.array == [Child { child_int: 9 }, Child { child_int: 10 }]);
+}
+
+fn assert_dto(dto: &EntityDto) {
+    let expected = self::dto();
+    assert_eq!(expected.children, dto.children);
+    assert_eq!(expected.set, dto.set);
+    assert_eq!(expected.map, dto.map);
+    assert_eq!(expected.tree, dto.tree);
+    assert_eq!(expected.keys, dto.keys);
+    assert_eq!(expected.array, dto.array);
+}
+
+#[test]
+fn named2named() {
+    let entity: Entity = dto().try_into().unwrap();
+    assert_entity(&entity);
+}
+
+#[test]
+fn named2named_reverse() {
+    let dto: EntityDto = entity().try_into().unwrap();
+    assert_dto(&dto);
+}
+
+#[test]
+fn named2named_ref() {
+    let entity: Entity = (&dto()).try_into().unwrap();
+    assert_entity(&entity);
+}
+
+#[test]
+fn named2named_ref_reverse() {
+    let dto: EntityDto = (&entity()).try_into().unwrap();
+    assert_dto(&dto);
+}
+
+#[test]
+fn existing_named2named() {
+    let mut entity = Entity::default();
+    dto().try_into_existing(&mut entity).unwrap();
+    assert_entity(&entity);
+}
+
+#[test]
+fn existing_named2named_ref() {
+    let mut entity = Entity::default();
+    (&dto()).try_into_existing(&mut entity).unwrap();
+    assert_entity(&entity);
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let dto = TupleEntityDto(123, vec![ChildDto { child_int: 321 }]);
+
+    let entity: TupleEntity = (&dto).try_into().unwrap();
+    assert_eq!(123, entity.0);
+    assert!(entity.1 == vec![Child { child_int: 321 }]);
+
+    let dto: TupleEntityDto = entity.try_into().unwrap();
+    assert_eq!(123, dto.0);
+    assert_eq!(vec![ChildDto { child_int: 321 }], dto.1);
+
+    let mut entity = TupleEntity::default();
+    dto.try_into_existing(&mut entity).unwrap();
+    assert_eq!(123, entity.0);
+    assert!(entity.1 == vec![Child { child_int: 321 }]);
+}
+
+#[test]
+fn enum2enum() {
+    let en = Enum::Var1(vec![Child { child_int: 123 }]);
+
+    let dto: EnumDto = (&en).try_into().unwrap();
+    let EnumDto::Var1(children) = &dto else { panic!() };
+    assert_eq!(&vec![ChildDto { child_int: 123 }], children);
+
+    let en: Enum = dto.try_into().unwrap();
+    let Enum::Var1(children) = en else { panic!() };
+    assert!(children == vec![Child { child_int: 123 }]);
+
+    let dto = EnumDto::Var2 { map: HashMap::from([("a".into(), ChildDto { child_int: 123 })]) };
+
+    let en: Enum = (&dto).try_into().unwrap();
+    let Enum::Var2 { map } = &en else { panic!() };
+    assert!(map == &HashMap::from([("a".into(), Child { child_int: 123 })]));
+
+    let dto: EnumDto = en.try_into().unwrap();
+    let EnumDto::Var2 { map } = dto else { panic!() };
+    assert_eq!(HashMap::from([("a".into(), ChildDto { child_int: 123 })]), map);
+}
+
+#[test]
+fn named2named_error() {
+    let mut entity = entity();
+    entity.children.push(Child { child_int: -1 });
+
+    let dto: Result<EntityDto, String> = (&entity).try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+
+    let dto: Result<EntityDto, String> = entity.try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+}
+
+#[test]
+fn named2named_array_error() {
+    let mut entity = entity();
+    entity.array[1] = Child { child_int: -1 };
+
+    let dto: Result<EntityDto, String> = (&entity).try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+
+    let dto: Result<EntityDto, String> = entity.try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+}
+
+#[test]
+fn named2named_map_error() {
+    let mut entity = entity();
+    entity.tree.insert(Key(-1), Child { child_int: -1 });
+
+    let dto: Result<EntityDto, String> = (&entity).try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+
+    let dto: Result<EntityDto, String> = entity.try_into();
+    assert_eq!(Err("negative".into()), dto.map(|_| ()));
+}
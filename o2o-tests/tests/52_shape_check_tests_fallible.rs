@@ -0,0 +1,104 @@
+use o2o::o2o;
+use o2o::traits::{Shape, TryIntoExisting};
+
+#[derive(Default, o2o::Shape)]
+struct Entity {
+    some_int: i32,
+    r#type: i32,
+    base: Base,
+}
+
+#[derive(Default, o2o::Shape)]
+struct Base {
+    base_int: i32,
+}
+
+#[derive(Default, o2o::Shape)]
+struct TupleEntity(i32, i16);
+
+#[derive(o2o::Shape)]
+struct Wrapper<T>(T);
+
+#[derive(o2o)]
+#[try_map(Entity, String| check_shape)]
+#[try_into_existing(Entity, String| check_shape)]
+#[child_parents(base: Base)]
+struct EntityDto {
+    some_int: i32,
+    #[into(r#type, ~ as i32)]
+    #[from(r#type, ~ as i16)]
+    another_int: i16,
+    #[child(base)]
+    #[map(base_int)]
+    base_int_2: i32,
+}
+
+#[derive(o2o)]
+#[try_map(TupleEntity, String| check_shape)]
+struct TupleEntityDto(i32, i16);
+
+#[derive(o2o)]
+#[try_from(Entity, String| check_shape)]
+#[try_into(Entity, String| check_shape, ..Default::default())]
+#[ghosts(r#type: { @.some_int * 2 })]
+struct PartialDto {
+    some_int: i32,
+}
+
+#[test]
+fn shape_fields() {
+    assert_eq!(&["some_int", "type", "base"], Entity::FIELDS);
+    assert_eq!(&["base_int"], Base::FIELDS);
+    assert_eq!(&["0", "1"], TupleEntity::FIELDS);
+    assert_eq!(&["0"], Wrapper::<i32>::FIELDS);
+}
+
+#[test]
+fn named2named() {
+    let dto = EntityDto { some_int: 123, another_int: 321, base_int_2: 456 };
+
+    let entity: Entity = dto.try_into().unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.r#type);
+    assert_eq!(456, entity.base.base_int);
+
+    let dto: EntityDto = (&entity).try_into().unwrap();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+    assert_eq!(456, dto.base_int_2);
+
+    let mut entity = Entity::default();
+    dto.try_into_existing(&mut entity).unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.r#type);
+    assert_eq!(456, entity.base.base_int);
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let entity: TupleEntity = TupleEntityDto(123, 321).try_into().unwrap();
+
+    assert_eq!(123, entity.0);
+    assert_eq!(321, entity.1);
+
+    let dto: TupleEntityDto = entity.try_into().unwrap();
+
+    assert_eq!(123, dto.0);
+    assert_eq!(321, dto.1);
+}
+
+#[test]
+fn update_syntax() {
+    let entity: Entity = PartialDto { some_int: 123 }.try_into().unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(246, entity.r#type);
+    assert_eq!(0, entity.base.base_int);
+
+    let dto: PartialDto = entity.try_into().unwrap();
+
+    assert_eq!(123, dto.some_int);
+}
@@ -0,0 +1,146 @@
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Default)]
+struct Entity {
+    dto_some_int: i32,
+    dto_another_int: i32,
+    dto_type: i32,
+    other_int: i32,
+    base: Base,
+}
+
+#[derive(Default)]
+struct Base {
+    dto_base_int: i32,
+}
+
+#[derive(Default)]
+#[allow(non_snake_case)]
+struct CamelEntity {
+    someInt: i32,
+    anotherInt: i32,
+}
+
+#[derive(o2o)]
+#[map(Entity| rename_all("dto_{}"))]
+#[into_existing(Entity| rename_all("dto_{}"))]
+#[child_parents(base: Base)]
+struct EntityDto {
+    some_int: i32,
+    #[map(~ * 2)]
+    another_int: i32,
+    r#type: i32,
+    #[map(other_int)]
+    different_int: i32,
+    #[child(base)]
+    base_int: i32,
+}
+
+#[derive(o2o)]
+#[map(CamelEntity| rename_all("camelCase"))]
+struct CamelEntityDto {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[map(CamelEntityDto| rename_all("snake_case"))]
+#[allow(non_snake_case)]
+struct CamelModel {
+    someInt: i32,
+    anotherInt: i32,
+}
+
+enum Enum {
+    Var1 { dto_some_int: i32 },
+}
+
+#[derive(o2o)]
+#[map(Enum| rename_all("dto_{}"))]
+enum EnumDto {
+    Var1 {
+        #[map_ref(*~)]
+        some_int: i32,
+    },
+}
+
+fn dto() -> EntityDto {
+    EntityDto { some_int: 123, another_int: 321, r#type: 456, different_int: 654, base_int: 789 }
+}
+
+fn assert_entity(entity: &Entity) {
+    assert_eq!(123, entity.dto_some_int);
+    assert_eq!(642, entity.dto_another_int);
+    assert_eq!(456, entity.dto_type);
+    assert_eq!(654, entity.other_int);
+    assert_eq!(789, entity.base.dto_base_int);
+}
+
+#[test]
+fn named2named() {
+    let entity: Entity = dto().into();
+    assert_entity(&entity);
+
+    let dto: EntityDto = entity.into();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(1284, dto.another_int);
+    assert_eq!(456, dto.r#type);
+    assert_eq!(654, dto.different_int);
+    assert_eq!(789, dto.base_int);
+}
+
+#[test]
+fn named2named_ref() {
+    let entity: Entity = (&dto()).into();
+    assert_entity(&entity);
+
+    let dto: EntityDto = (&entity).into();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(1284, dto.another_int);
+    assert_eq!(456, dto.r#type);
+    assert_eq!(654, dto.different_int);
+    assert_eq!(789, dto.base_int);
+}
+
+#[test]
+fn existing_named2named() {
+    let mut entity = Entity::default();
+    dto().into_existing(&mut entity);
+    assert_entity(&entity);
+
+    let mut entity = Entity::default();
+    (&dto()).into_existing(&mut entity);
+    assert_entity(&entity);
+}
+
+#[test]
+fn case_styles() {
+    let entity: CamelEntity = CamelEntityDto { some_int: 123, another_int: 321 }.into();
+
+    assert_eq!(123, entity.someInt);
+    assert_eq!(321, entity.anotherInt);
+
+    let dto: CamelEntityDto = entity.into();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+
+    let model: CamelModel = dto.into();
+
+    assert_eq!(123, model.someInt);
+    assert_eq!(321, model.anotherInt);
+}
+
+#[test]
+fn enum2enum() {
+    let en: Enum = EnumDto::Var1 { some_int: 123 }.into();
+    let Enum::Var1 { dto_some_int } = en;
+    assert_eq!(123, dto_some_int);
+
+    let dto: EnumDto = (&Enum::Var1 { dto_some_int: 321 }).into();
+    let EnumDto::Var1 { some_int } = dto;
+    assert_eq!(321, some_int);
+}
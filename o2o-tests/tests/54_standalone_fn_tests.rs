@@ -0,0 +1,110 @@
+use o2o::o2o;
+
+#[derive(Default)]
+struct Entity {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[map(Entity)]
+#[from_owned(Entity| fn(from_entity_negated), return Self { some_int: -@.some_int, another_int: -@.another_int })]
+#[from_ref(Entity| fn(pub from_entity_ref))]
+#[owned_into(Entity| fn(into_entity))]
+#[ref_into(Entity| fn(pub(crate) to_entity))]
+#[owned_into_existing(Entity| fn(apply_to))]
+#[ref_into_existing(Entity| fn(apply_ref_to))]
+struct EntityDto {
+    some_int: i32,
+    another_int: i32,
+}
+
+struct Wrapper(i32);
+
+#[derive(o2o)]
+#[from_owned(Wrapper| fn(from_wrapper))]
+#[owned_into(Wrapper| fn(into_wrapper))]
+struct WrapperDto(i32);
+
+#[derive(Clone, Copy)]
+enum Enum {
+    Var1,
+    Var2(i32),
+}
+
+#[derive(o2o)]
+#[from_owned(Enum| fn(from_enum))]
+#[ref_into(Enum| fn(to_enum))]
+enum EnumDto {
+    Var1,
+    Var2(#[ref_into(*~)] i32),
+}
+
+#[test]
+fn from() {
+    let entity = Entity { some_int: 123, another_int: 321 };
+
+    let dto = EntityDto::from_entity_ref(&entity);
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+
+    let dto = EntityDto::from_entity_negated(entity);
+    assert_eq!(-123, dto.some_int);
+    assert_eq!(-321, dto.another_int);
+}
+
+#[test]
+fn into() {
+    let dto = EntityDto { some_int: 123, another_int: 321 };
+
+    let entity = dto.to_entity();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let entity = dto.into_entity();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn into_existing() {
+    let dto = EntityDto { some_int: 123, another_int: 321 };
+
+    let mut entity = Entity::default();
+    dto.apply_ref_to(&mut entity);
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let mut entity = Entity::default();
+    dto.apply_to(&mut entity);
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn trait_impls_are_kept() {
+    let dto: EntityDto = Entity { some_int: 123, another_int: 321 }.into();
+    assert_eq!(123, dto.some_int);
+
+    let entity: Entity = dto.into();
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let dto = WrapperDto::from_wrapper(Wrapper(123));
+    assert_eq!(123, dto.0);
+
+    let wrapper = dto.into_wrapper();
+    assert_eq!(123, wrapper.0);
+}
+
+#[test]
+fn enum2enum() {
+    let dto = EnumDto::from_enum(Enum::Var2(123));
+    let Enum::Var2(x) = dto.to_enum() else { panic!() };
+    assert_eq!(123, x);
+
+    let dto = EnumDto::from_enum(Enum::Var1);
+    assert!(matches!(dto.to_enum(), Enum::Var1));
+}
@@ -0,0 +1,109 @@
+use o2o::o2o;
+
+#[derive(Default)]
+struct Entity {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[try_map(Entity, String)]
+#[try_from_owned(Entity, String| fn(try_from_entity_negated), return Ok(Self { some_int: -@.some_int, another_int: -@.another_int }))]
+#[try_from_ref(Entity, String| fn(pub try_from_entity_ref))]
+#[owned_try_into(Entity, String| fn(try_into_entity))]
+#[ref_try_into(Entity, String| fn(pub(crate) try_to_entity))]
+#[owned_try_into_existing(Entity, String| fn(try_apply_to))]
+#[ref_try_into_existing(Entity, String| fn(try_apply_ref_to))]
+struct EntityDto {
+    some_int: i32,
+    another_int: i32,
+}
+
+struct Wrapper(i32);
+
+#[derive(o2o)]
+#[try_from_owned(Wrapper, String| fn(try_from_wrapper))]
+#[owned_try_into(Wrapper, String| fn(try_into_wrapper))]
+struct WrapperDto(i32);
+
+enum Enum {
+    Var1,
+    Var2(i32),
+}
+
+#[derive(o2o)]
+#[try_from_owned(Enum, String| fn(try_from_enum))]
+#[ref_try_into(Enum, String| fn(try_to_enum))]
+enum EnumDto {
+    Var1,
+    Var2(#[ref_into(*~)] i32),
+}
+
+#[test]
+fn from() {
+    let entity = Entity { some_int: 123, another_int: 321 };
+
+    let dto = EntityDto::try_from_entity_ref(&entity).unwrap();
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+
+    let dto = EntityDto::try_from_entity_negated(entity).unwrap();
+    assert_eq!(-123, dto.some_int);
+    assert_eq!(-321, dto.another_int);
+}
+
+#[test]
+fn into() {
+    let dto = EntityDto { some_int: 123, another_int: 321 };
+
+    let entity = dto.try_to_entity().unwrap();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let entity = dto.try_into_entity().unwrap();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn into_existing() {
+    let dto = EntityDto { some_int: 123, another_int: 321 };
+
+    let mut entity = Entity::default();
+    dto.try_apply_ref_to(&mut entity).unwrap();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let mut entity = Entity::default();
+    dto.try_apply_to(&mut entity).unwrap();
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn trait_impls_are_kept() {
+    let dto: EntityDto = Entity { some_int: 123, another_int: 321 }.try_into().unwrap();
+    assert_eq!(123, dto.some_int);
+
+    let entity: Entity = dto.try_into().unwrap();
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let dto = WrapperDto::try_from_wrapper(Wrapper(123)).unwrap();
+    assert_eq!(123, dto.0);
+
+    let wrapper = dto.try_into_wrapper().unwrap();
+    assert_eq!(123, wrapper.0);
+}
+
+#[test]
+fn enum2enum() {
+    let dto = EnumDto::try_from_enum(Enum::Var2(123)).unwrap();
+    let Enum::Var2(x) = dto.try_to_enum().unwrap() else { panic!() };
+    assert_eq!(123, x);
+
+    let dto = EnumDto::try_from_enum(Enum::Var1).unwrap();
+    assert!(matches!(dto.try_to_enum().unwrap(), Enum::Var1));
+}
@@ -0,0 +1,148 @@
+use o2o::o2o;
+
+mod foreign {
+    pub struct Entity {
+        pub some_int: i32,
+        pub another_int: i32,
+    }
+
+    pub struct Model {
+        pub some_int: i32,
+        pub different_int: i32,
+    }
+
+    pub struct TupleEntity(pub i32, pub i32);
+
+    pub enum Enum {
+        Var1,
+        Var2 { some_int: i32 },
+    }
+
+    pub enum EnumModel {
+        Var1,
+        Var2 { value: i32 },
+    }
+}
+
+struct EntityDto {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[o2o(remote(foreign::Entity))]
+#[map_owned(EntityDto)]
+#[from_owned(foreign::Model| fn(from_model))]
+#[from_ref(foreign::Model| fn(from_model_ref))]
+#[owned_into(foreign::Model| fn(into_model))]
+#[ref_into(foreign::Model| fn(to_model))]
+#[ref_into_existing(foreign::Model| fn(apply_to_model))]
+#[allow(dead_code)]
+struct EntityDef {
+    some_int: i32,
+    #[map(foreign::Model| different_int)]
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[o2o(remote(foreign::TupleEntity))]
+#[from_ref(foreign::Model as {}| fn(from_model))]
+#[owned_into(foreign::Model as {}| fn(into_model))]
+#[allow(dead_code)]
+struct TupleEntityDef(#[map(some_int)] i32, #[map(different_int)] i32);
+
+#[derive(o2o)]
+#[o2o(remote(foreign::Enum))]
+#[from_owned(foreign::EnumModel| fn(from_model))]
+#[ref_into(foreign::EnumModel| fn(to_model))]
+#[allow(dead_code)]
+enum EnumDef {
+    Var1,
+    Var2 {
+        #[from(value)]
+        #[into(value, *~)]
+        some_int: i32,
+    },
+}
+
+fn model() -> foreign::Model {
+    foreign::Model { some_int: 123, different_int: 321 }
+}
+
+#[test]
+fn from() {
+    let entity: foreign::Entity = EntityDef::from_model(model());
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let entity: foreign::Entity = EntityDef::from_model_ref(&model());
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn into() {
+    let entity = EntityDef::from_model(model());
+
+    let model: foreign::Model = EntityDef::to_model(&entity);
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+
+    let model: foreign::Model = EntityDef::into_model(entity);
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn into_existing() {
+    let entity = EntityDef::from_model(model());
+
+    let mut model = foreign::Model { some_int: 0, different_int: 0 };
+    EntityDef::apply_to_model(&entity, &mut model);
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn trait_impls() {
+    let entity: foreign::Entity = EntityDto { some_int: 123, another_int: 321 }.into();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let dto: EntityDto = entity.into();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+}
+
+#[test]
+fn unnamed2named() {
+    let entity: foreign::TupleEntity = TupleEntityDef::from_model(&model());
+
+    assert_eq!(123, entity.0);
+    assert_eq!(321, entity.1);
+
+    let model: foreign::Model = TupleEntityDef::into_model(entity);
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn enum2enum() {
+    let en: foreign::Enum = EnumDef::from_model(foreign::EnumModel::Var2 { value: 123 });
+    let foreign::Enum::Var2 { some_int } = &en else { panic!() };
+    assert_eq!(123, *some_int);
+
+    let foreign::EnumModel::Var2 { value: x } = EnumDef::to_model(&en) else { panic!() };
+    assert_eq!(123, x);
+
+    let en: foreign::Enum = EnumDef::from_model(foreign::EnumModel::Var1);
+    assert!(matches!(EnumDef::to_model(&en), foreign::EnumModel::Var1));
+}
@@ -0,0 +1,148 @@
+use o2o::o2o;
+
+mod foreign {
+    pub struct Entity {
+        pub some_int: i32,
+        pub another_int: i32,
+    }
+
+    pub struct Model {
+        pub some_int: i32,
+        pub different_int: i32,
+    }
+
+    pub struct TupleEntity(pub i32, pub i32);
+
+    pub enum Enum {
+        Var1,
+        Var2 { some_int: i32 },
+    }
+
+    pub enum EnumModel {
+        Var1,
+        Var2 { value: i32 },
+    }
+}
+
+struct EntityDto {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[o2o(remote(foreign::Entity))]
+#[try_map_owned(EntityDto, String)]
+#[try_from_owned(foreign::Model, String| fn(try_from_model))]
+#[try_from_ref(foreign::Model, String| fn(try_from_model_ref))]
+#[owned_try_into(foreign::Model, String| fn(try_into_model))]
+#[ref_try_into(foreign::Model, String| fn(try_to_model))]
+#[ref_try_into_existing(foreign::Model, String| fn(try_apply_to_model))]
+#[allow(dead_code)]
+struct EntityDef {
+    some_int: i32,
+    #[map(foreign::Model| different_int)]
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[o2o(remote(foreign::TupleEntity))]
+#[try_from_ref(foreign::Model as {}, String| fn(try_from_model))]
+#[owned_try_into(foreign::Model as {}, String| fn(try_into_model))]
+#[allow(dead_code)]
+struct TupleEntityDef(#[map(some_int)] i32, #[map(different_int)] i32);
+
+#[derive(o2o)]
+#[o2o(remote(foreign::Enum))]
+#[try_from_owned(foreign::EnumModel, String| fn(try_from_model))]
+#[ref_try_into(foreign::EnumModel, String| fn(try_to_model))]
+#[allow(dead_code)]
+enum EnumDef {
+    Var1,
+    Var2 {
+        #[from(value)]
+        #[into(value, *~)]
+        some_int: i32,
+    },
+}
+
+fn model() -> foreign::Model {
+    foreign::Model { some_int: 123, different_int: 321 }
+}
+
+#[test]
+fn from() {
+    let entity: foreign::Entity = EntityDef::try_from_model(model()).unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let entity: foreign::Entity = EntityDef::try_from_model_ref(&model()).unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+}
+
+#[test]
+fn into() {
+    let entity = EntityDef::try_from_model(model()).unwrap();
+
+    let model: foreign::Model = EntityDef::try_to_model(&entity).unwrap();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+
+    let model: foreign::Model = EntityDef::try_into_model(entity).unwrap();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn into_existing() {
+    let entity = EntityDef::try_from_model(model()).unwrap();
+
+    let mut model = foreign::Model { some_int: 0, different_int: 0 };
+    EntityDef::try_apply_to_model(&entity, &mut model).unwrap();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn trait_impls() {
+    let entity: foreign::Entity = EntityDto { some_int: 123, another_int: 321 }.try_into().unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+
+    let dto: EntityDto = entity.try_into().unwrap();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+}
+
+#[test]
+fn unnamed2named() {
+    let entity: foreign::TupleEntity = TupleEntityDef::try_from_model(&model()).unwrap();
+
+    assert_eq!(123, entity.0);
+    assert_eq!(321, entity.1);
+
+    let model: foreign::Model = TupleEntityDef::try_into_model(entity).unwrap();
+
+    assert_eq!(123, model.some_int);
+    assert_eq!(321, model.different_int);
+}
+
+#[test]
+fn enum2enum() {
+    let en: foreign::Enum = EnumDef::try_from_model(foreign::EnumModel::Var2 { value: 123 }).unwrap();
+    let foreign::Enum::Var2 { some_int } = &en else { panic!() };
+    assert_eq!(123, *some_int);
+
+    let foreign::EnumModel::Var2 { value: x } = EnumDef::try_to_model(&en).unwrap() else { panic!() };
+    assert_eq!(123, x);
+
+    let en: foreign::Enum = EnumDef::try_from_model(foreign::EnumModel::Var1).unwrap();
+    assert!(matches!(EnumDef::try_to_model(&en).unwrap(), foreign::EnumModel::Var1));
+}
@@ -0,0 +1,137 @@
+use o2o::o2o;
+use o2o::traits::{ConversionErrors, ErrorCollector};
+
+struct Entity {
+    some_int: i64,
+    another_int: i64,
+    text: String,
+}
+
+#[derive(Debug, o2o)]
+#[try_from(Entity, String| collect_errors)]
+#[try_into(Entity, String| collect_errors)]
+struct EntityDto {
+    #[from(~.try_into().map_err(|_| "out of range".to_string())?)]
+    #[into(~.into())]
+    some_int: i32,
+    #[from(~.try_into().map_err(|_| "out of range".to_string())?)]
+    #[into(~.into())]
+    another_int: i16,
+    #[from(text, ~.parse().map_err(|_| format!("'{}' is not a number", ~))?)]
+    #[into(text, ~.to_string())]
+    number: u8,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_owned(Entity, String| collect_errors, ..get_default())]
+struct PartialDto {
+    #[from(~.try_into().map_err(|_| "out of range".to_string())?)]
+    another_int: i16,
+    #[ghost(123)]
+    ghost: i32,
+    #[ghost]
+    unmapped: i32,
+    text: String,
+}
+
+fn get_default() -> PartialDto {
+    PartialDto { another_int: 0, ghost: 0, unmapped: 42, text: String::new() }
+}
+
+struct TupleEntity(i64, i64);
+
+#[derive(Debug, o2o)]
+#[try_from_ref(TupleEntity, String| collect_errors)]
+struct TupleEntityDto(
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)] i32,
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)] i8,
+);
+
+#[derive(Default, Debug, PartialEq)]
+struct ErrorList(Vec<String>);
+
+impl ErrorCollector<String> for ErrorList {
+    fn push(&mut self, field: &'static str, error: String) {
+        self.0.push(format!("{field}: {error}"));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Debug, o2o)]
+#[try_from_ref(Entity, String| collect_errors(ErrorList))]
+struct CustomCollectorDto {
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)]
+    some_int: i8,
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)]
+    another_int: i8,
+}
+
+#[test]
+fn all_fields_ok() {
+    let entity = Entity { some_int: 123, another_int: 321, text: "42".into() };
+
+    let dto: EntityDto = entity.try_into().unwrap();
+
+    assert_eq!(123, dto.some_int);
+    assert_eq!(321, dto.another_int);
+    assert_eq!(42, dto.number);
+
+    let entity: Entity = dto.try_into().unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(321, entity.another_int);
+    assert_eq!("42", entity.text);
+}
+
+#[test]
+fn all_errors_are_collected() {
+    let entity = Entity { some_int: i64::MAX, another_int: 321, text: "abc".into() };
+
+    let errors: ConversionErrors<String> = EntityDto::try_from(entity).unwrap_err();
+
+    assert_eq!(vec![("some_int", "out of range".to_string()), ("number", "'abc' is not a number".to_string())], errors.errors);
+    assert_eq!("some_int: out of range; number: 'abc' is not a number", errors.to_string());
+}
+
+#[test]
+fn update_syntax() {
+    let dto: PartialDto = Entity { some_int: 0, another_int: 321, text: "abc".into() }.try_into().unwrap();
+
+    assert_eq!(321, dto.another_int);
+    assert_eq!(123, dto.ghost);
+    assert_eq!(42, dto.unmapped);
+    assert_eq!("abc", dto.text);
+
+    let errors = PartialDto::try_from(Entity { some_int: 0, another_int: i64::MIN, text: "abc".into() }).unwrap_err();
+
+    assert_eq!(vec![("another_int", "out of range".to_string())], errors.errors);
+}
+
+#[test]
+fn unnamed2unnamed() {
+    let dto = TupleEntityDto::try_from(&TupleEntity(123, 12)).unwrap();
+
+    assert_eq!(123, dto.0);
+    assert_eq!(12, dto.1);
+
+    let errors = TupleEntityDto::try_from(&TupleEntity(i64::MAX, 1000)).unwrap_err();
+
+    assert_eq!(vec![("0", "out of range".to_string()), ("1", "out of range".to_string())], errors.errors);
+}
+
+#[test]
+fn custom_collector() {
+    let dto = CustomCollectorDto::try_from(&Entity { some_int: 100, another_int: -100, text: String::new() }).unwrap();
+
+    assert_eq!(100, dto.some_int);
+    assert_eq!(-100, dto.another_int);
+
+    let entity = Entity { some_int: 1000, another_int: 2000, text: String::new() };
+
+    let errors = CustomCollectorDto::try_from(&entity).unwrap_err();
+
+    assert_eq!(ErrorList(vec!["some_int: out of range".into(), "another_int: out of range".into()]), errors);
+}
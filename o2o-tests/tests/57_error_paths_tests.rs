@@ -0,0 +1,160 @@
+use o2o::o2o;
+use o2o::traits::{FieldError, TryIntoExisting};
+
+#[derive(Default)]
+struct Person {
+    name: String,
+    age: i64,
+    address: Address,
+}
+
+#[derive(Default)]
+struct Address {
+    street: String,
+    zip_code: String,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_owned(Person, String| error_paths)]
+#[owned_try_into(Person, String| error_paths)]
+#[owned_try_into_existing(Person, String| error_paths)]
+#[child_parents(address: Address)]
+struct PersonDto {
+    name: String,
+    #[from(~.try_into().map_err(|_| "out of range".to_string())?)]
+    #[into(~.into())]
+    age: u8,
+    #[child(address)]
+    street: String,
+    #[child(address)]
+    #[from(zip_code, ~.parse().map_err(|_| "invalid".to_string())?)]
+    #[into(zip_code, ~.to_string())]
+    zip: u32,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_ref(Person, String| error_paths)]
+struct FlatPersonDto {
+    #[from(~.clone())]
+    name: String,
+    #[parent]
+    address: AddressDto,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_ref(Person, String| error_paths)]
+struct AddressDto {
+    #[from(@.address.zip_code.parse().map_err(|_| "invalid".to_string())?)]
+    zip_code: u32,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_ref(Person, String| error_paths)]
+struct FlatPersonPlainDto {
+    #[parent]
+    address: PlainAddressDto,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_ref(Person, String)]
+struct PlainAddressDto {
+    #[from(@.address.zip_code.parse().map_err(|_| "invalid".to_string())?)]
+    zip_code: u32,
+}
+
+struct TupleEntity(i64, i64);
+
+#[derive(Debug, o2o)]
+#[try_from_owned(TupleEntity, String| error_paths)]
+struct TupleEntityDto(
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)] i32,
+    #[from(TryInto::try_into(~).map_err(|_| "out of range".to_string())?)] i8,
+);
+
+#[test]
+fn ok() {
+    let person = Person { name: "John".into(), age: 42, address: Address { street: "Main".into(), zip_code: "12345".into() } };
+
+    let dto: PersonDto = person.try_into().unwrap();
+
+    assert_eq!("John", dto.name);
+    assert_eq!(42, dto.age);
+    assert_eq!("Main", dto.street);
+    assert_eq!(12345, dto.zip);
+
+    let person: Person = dto.try_into().unwrap();
+
+    assert_eq!("John", person.name);
+    assert_eq!(42, person.age);
+    assert_eq!("Main", person.address.street);
+    assert_eq!("12345", person.address.zip_code);
+
+    let dto = FlatPersonDto::try_from(&person).unwrap();
+
+    assert_eq!("John", dto.name);
+    assert_eq!(12345, dto.address.zip_code);
+
+    let dto = FlatPersonPlainDto::try_from(&person).unwrap();
+
+    assert_eq!(12345, dto.address.zip_code);
+
+    let dto = TupleEntityDto::try_from(TupleEntity(1, 2)).unwrap();
+
+    assert_eq!(1, dto.0);
+    assert_eq!(2, dto.1);
+}
+
+#[test]
+fn field_error() {
+    let person = Person { name: "John".into(), age: 420, address: Address { street: "Main".into(), zip_code: "12345".into() } };
+
+    let error = PersonDto::try_from(person).unwrap_err();
+
+    assert_eq!(FieldError::new("age", "out of range".to_string()), error);
+    assert_eq!("age: out of range", error.to_string());
+}
+
+#[test]
+fn child_field_error() {
+    let person = Person { name: "John".into(), age: 42, address: Address { street: "Main".into(), zip_code: "abc".into() } };
+
+    let error = PersonDto::try_from(person).unwrap_err();
+
+    assert_eq!("address.zip_code", error.path);
+    assert_eq!("address.zip_code: invalid", error.to_string());
+}
+
+#[test]
+fn parent_field_error() {
+    let person = Person { name: "John".into(), age: 42, address: Address { street: "Main".into(), zip_code: "abc".into() } };
+
+    let error = FlatPersonDto::try_from(&person).unwrap_err();
+
+    assert_eq!("address.zip_code", error.path);
+    assert_eq!("address.zip_code: invalid", error.to_string());
+
+    let error = FlatPersonPlainDto::try_from(&person).unwrap_err();
+
+    assert_eq!("address", error.path);
+    assert_eq!("invalid", error.error);
+}
+
+#[test]
+fn tuple_field_error() {
+    let error = TupleEntityDto::try_from(TupleEntity(1, 1000)).unwrap_err();
+
+    assert_eq!("1: out of range", error.to_string());
+}
+
+#[test]
+fn existing() {
+    let dto = PersonDto { name: "John".into(), age: 42, street: "Main".into(), zip: 12345 };
+
+    let mut person = Person::default();
+    dto.try_into_existing(&mut person).unwrap();
+
+    assert_eq!("John", person.name);
+    assert_eq!(42, person.age);
+    assert_eq!("Main", person.address.street);
+    assert_eq!("12345", person.address.zip_code);
+}
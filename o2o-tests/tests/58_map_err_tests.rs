@@ -0,0 +1,128 @@
+use o2o::o2o;
+use o2o::traits::TryIntoExisting;
+
+#[derive(Debug, PartialEq)]
+enum MyErr {
+    BadAge(std::num::ParseIntError),
+    BadZip(std::num::ParseIntError),
+    OutOfRange,
+    Other(String),
+}
+
+impl From<std::num::TryFromIntError> for MyErr {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        MyErr::OutOfRange
+    }
+}
+
+#[derive(Default)]
+struct Entity {
+    age: String,
+    zip: String,
+    some_int: i64,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_owned(Entity, MyErr)]
+#[owned_try_into(Entity, MyErr)]
+#[owned_try_into_existing(Entity, MyErr)]
+struct EntityDto {
+    #[from(~.parse(), map_err = MyErr::BadAge)]
+    #[into(~.to_string())]
+    age: u8,
+    #[from(~.parse()?, map_err = MyErr::BadZip)]
+    #[into(~.to_string())]
+    zip: u32,
+    #[from(~.try_into()?)]
+    #[into(~.into())]
+    some_int: i32,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_owned(Entity, MyErr| map_err(MyErr::BadAge))]
+struct AgeDto {
+    #[from(~.parse()?)]
+    age: u8,
+    #[from(zip, ~.parse()?, map_err = |e: std::num::ParseIntError| MyErr::Other(e.to_string()))]
+    zip_code: u32,
+}
+
+#[derive(Default)]
+struct StringEntity {
+    some_int: String,
+}
+
+#[derive(Debug, o2o)]
+#[owned_try_into(StringEntity, MyErr)]
+#[owned_try_into_existing(StringEntity, MyErr)]
+struct StringEntityDto {
+    #[into(String::from_utf8(~), map_err = |e: std::string::FromUtf8Error| MyErr::Other(e.to_string()))]
+    some_int: Vec<u8>,
+}
+
+#[test]
+fn ok() {
+    let entity = Entity { age: "42".into(), zip: "12345".into(), some_int: 123 };
+
+    let dto: EntityDto = entity.try_into().unwrap();
+
+    assert_eq!(42, dto.age);
+    assert_eq!(12345, dto.zip);
+    assert_eq!(123, dto.some_int);
+
+    let entity: Entity = dto.try_into().unwrap();
+
+    assert_eq!("42", entity.age);
+    assert_eq!("12345", entity.zip);
+    assert_eq!(123, entity.some_int);
+}
+
+#[test]
+fn member_map_err() {
+    let entity = Entity { age: "abc".into(), zip: "12345".into(), some_int: 123 };
+    let err = EntityDto::try_from(entity).unwrap_err();
+    assert!(matches!(err, MyErr::BadAge(_)));
+
+    let entity = Entity { age: "42".into(), zip: "abc".into(), some_int: 123 };
+    let err = EntityDto::try_from(entity).unwrap_err();
+    assert!(matches!(err, MyErr::BadZip(_)));
+
+    let entity = Entity { age: "42".into(), zip: "12345".into(), some_int: i64::MAX };
+    let err = EntityDto::try_from(entity).unwrap_err();
+    assert_eq!(MyErr::OutOfRange, err);
+}
+
+#[test]
+fn trait_map_err() {
+    let entity = Entity { age: "42".into(), zip: "12345".into(), some_int: 123 };
+    let dto = AgeDto::try_from(entity).unwrap();
+    assert_eq!(42, dto.age);
+    assert_eq!(12345, dto.zip_code);
+
+    let entity = Entity { age: "abc".into(), zip: "12345".into(), some_int: 123 };
+    let err = AgeDto::try_from(entity).unwrap_err();
+    assert!(matches!(err, MyErr::BadAge(_)));
+
+    let entity = Entity { age: "42".into(), zip: "abc".into(), some_int: 123 };
+    let err = AgeDto::try_from(entity).unwrap_err();
+    assert_eq!(MyErr::Other("invalid digit found in string".into()), err);
+}
+
+#[test]
+fn into_map_err() {
+    let entity: StringEntity = StringEntityDto { some_int: b"123".to_vec() }.try_into().unwrap();
+    assert_eq!("123", entity.some_int);
+
+    let result: Result<StringEntity, MyErr> = StringEntityDto { some_int: vec![0xff] }.try_into();
+    let err = result.err().unwrap();
+    assert!(matches!(err, MyErr::Other(_)));
+
+    let mut entity = StringEntity::default();
+    let err = StringEntityDto { some_int: vec![0xff] }.try_into_existing(&mut entity).unwrap_err();
+    assert!(matches!(err, MyErr::Other(_)));
+
+    let dto = EntityDto { age: 42, zip: 12345, some_int: 123 };
+    let mut entity = Entity::default();
+    dto.try_into_existing(&mut entity).unwrap();
+    assert_eq!("42", entity.age);
+}
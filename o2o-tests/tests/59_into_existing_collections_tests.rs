@@ -0,0 +1,82 @@
+use std::collections::{BTreeMap, HashMap};
+
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Default, Debug, PartialEq)]
+struct Entity {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(Clone, o2o)]
+#[into_existing(Entity)]
+struct EntityPatch {
+    some_int: i32,
+}
+
+fn patch(some_int: i32) -> EntityPatch {
+    EntityPatch { some_int }
+}
+
+fn entity(some_int: i32, another_int: i32) -> Entity {
+    Entity { some_int, another_int }
+}
+
+#[test]
+fn option() {
+    let mut entity = entity(1, 2);
+
+    None::<EntityPatch>.into_existing(&mut entity);
+    assert_eq!(Entity { some_int: 1, another_int: 2 }, entity);
+
+    Some(patch(3)).into_existing(&mut entity);
+    assert_eq!(Entity { some_int: 3, another_int: 2 }, entity);
+
+    (&Some(patch(4))).into_existing(&mut entity);
+    assert_eq!(Entity { some_int: 4, another_int: 2 }, entity);
+
+    (&None::<EntityPatch>).into_existing(&mut entity);
+    assert_eq!(Entity { some_int: 4, another_int: 2 }, entity);
+}
+
+#[test]
+fn vec() {
+    let mut entities = vec![entity(1, 2), entity(3, 4), entity(5, 6)];
+
+    vec![patch(10), patch(30)].into_existing(&mut entities);
+    assert_eq!(vec![entity(10, 2), entity(30, 4)], entities);
+
+    (&vec![patch(100), patch(300), patch(500)]).into_existing(&mut entities);
+    assert_eq!(vec![entity(100, 2), entity(300, 4), entity(500, 0)], entities);
+}
+
+#[test]
+fn vec_of_options() {
+    let mut entities = vec![entity(1, 2), entity(3, 4)];
+
+    vec![None, Some(patch(30))].into_existing(&mut entities);
+    assert_eq!(vec![entity(1, 2), entity(30, 4)], entities);
+}
+
+#[test]
+fn btree_map() {
+    let mut entities = BTreeMap::from([("a", entity(1, 2)), ("b", entity(3, 4))]);
+
+    BTreeMap::from([("b", patch(30)), ("c", patch(50))]).into_existing(&mut entities);
+    assert_eq!(BTreeMap::from([("a", entity(1, 2)), ("b", entity(30, 4)), ("c", entity(50, 0))]), entities);
+
+    (&BTreeMap::from([("a", patch(10))])).into_existing(&mut entities);
+    assert_eq!(entity(10, 2), entities["a"]);
+}
+
+#[test]
+fn hash_map() {
+    let mut entities = HashMap::from([("a", entity(1, 2)), ("b", entity(3, 4))]);
+
+    HashMap::from([("b", patch(30)), ("c", patch(50))]).into_existing(&mut entities);
+    assert_eq!(HashMap::from([("a", entity(1, 2)), ("b", entity(30, 4)), ("c", entity(50, 0))]), entities);
+
+    (&HashMap::from([("a", patch(10))])).into_existing(&mut entities);
+    assert_eq!(entity(10, 2), entities["a"]);
+}
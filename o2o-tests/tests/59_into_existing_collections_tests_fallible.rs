@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, HashMap};
+
+use o2o::o2o;
+use o2o::traits::TryIntoExisting;
+
+#[derive(Default, Debug, PartialEq)]
+struct Entity {
+    some_int: i32,
+    another_int: i32,
+}
+
+#[derive(Clone, o2o)]
+#[try_into_existing(Entity, String)]
+struct EntityPatch {
+    #[into(~.try_into().map_err(|_| "out of range".to_string())?)]
+    some_int: i64,
+}
+
+fn patch(some_int: i64) -> EntityPatch {
+    EntityPatch { some_int }
+}
+
+fn entity(some_int: i32, another_int: i32) -> Entity {
+    Entity { some_int, another_int }
+}
+
+#[test]
+fn option() {
+    let mut entity = entity(1, 2);
+
+    None::<EntityPatch>.try_into_existing(&mut entity).unwrap();
+    assert_eq!(Entity { some_int: 1, another_int: 2 }, entity);
+
+    Some(patch(3)).try_into_existing(&mut entity).unwrap();
+    assert_eq!(Entity { some_int: 3, another_int: 2 }, entity);
+
+    (&Some(patch(4))).try_into_existing(&mut entity).unwrap();
+    assert_eq!(Entity { some_int: 4, another_int: 2 }, entity);
+
+    let err = Some(patch(i64::MAX)).try_into_existing(&mut entity).unwrap_err();
+    assert_eq!("out of range", err);
+}
+
+#[test]
+fn vec() {
+    let mut entities = vec![entity(1, 2), entity(3, 4), entity(5, 6)];
+
+    vec![patch(10), patch(30)].try_into_existing(&mut entities).unwrap();
+    assert_eq!(vec![entity(10, 2), entity(30, 4)], entities);
+
+    (&vec![patch(100), patch(300), patch(500)]).try_into_existing(&mut entities).unwrap();
+    assert_eq!(vec![entity(100, 2), entity(300, 4), entity(500, 0)], entities);
+
+    let err = vec![patch(1), patch(i64::MAX)].try_into_existing(&mut entities).unwrap_err();
+    assert_eq!("out of range", err);
+}
+
+#[test]
+fn btree_map() {
+    let mut entities = BTreeMap::from([("a", entity(1, 2)), ("b", entity(3, 4))]);
+
+    BTreeMap::from([("b", patch(30)), ("c", patch(50))]).try_into_existing(&mut entities).unwrap();
+    assert_eq!(BTreeMap::from([("a", entity(1, 2)), ("b", entity(30, 4)), ("c", entity(50, 0))]), entities);
+
+    let err = (&BTreeMap::from([("a", patch(i64::MAX))])).try_into_existing(&mut entities).unwrap_err();
+    assert_eq!("out of range", err);
+}
+
+#[test]
+fn hash_map() {
+    let mut entities = HashMap::from([("a", entity(1, 2)), ("b", entity(3, 4))]);
+
+    HashMap::from([("b", patch(30)), ("c", patch(50))]).try_into_existing(&mut entities).unwrap();
+    assert_eq!(HashMap::from([("a", entity(1, 2)), ("b", entity(30, 4)), ("c", entity(50, 0))]), entities);
+
+    let err = (&HashMap::from([("a", patch(i64::MAX))])).try_into_existing(&mut entities).unwrap_err();
+    assert_eq!("out of range", err);
+}
@@ -0,0 +1,55 @@
+use o2o::o2o;
+
+#[derive(Default)]
+struct Entity {
+    some_int: i32,
+    created_at: u64,
+    tenant: String,
+    child: Child,
+}
+
+#[derive(Default)]
+struct Child {
+    child_int: i32,
+    request_id: u32,
+}
+
+#[derive(o2o)]
+#[owned_into(Entity)]
+#[child_parents(child: Child)]
+#[ghosts(created_at as u64, tenant as String, child@request_id as u32)]
+struct EntityDto {
+    some_int: i32,
+    #[child(child)]
+    child_int: i32,
+}
+
+#[derive(o2o)]
+#[owned_into(Entity| fn(pub(crate) into_entity), ..Default::default())]
+#[ghosts(created_at as u64, tenant: { "default".into() })]
+struct PartialDto {
+    some_int: i32,
+}
+
+#[test]
+fn builder() {
+    let dto = EntityDto { some_int: 123, child_int: 456 };
+
+    let entity: Entity = dto.into_entity_builder().tenant("tenant".into()).created_at(42).request_id(789).build();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(42, entity.created_at);
+    assert_eq!("tenant", entity.tenant);
+    assert_eq!(456, entity.child.child_int);
+    assert_eq!(789, entity.child.request_id);
+}
+
+#[test]
+fn builder_with_custom_fn_name() {
+    let entity: Entity = PartialDto { some_int: 123 }.into_entity().created_at(42).build();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(42, entity.created_at);
+    assert_eq!("default", entity.tenant);
+    assert_eq!(0, entity.child.child_int);
+}
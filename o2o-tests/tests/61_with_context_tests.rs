@@ -0,0 +1,62 @@
+use o2o::o2o;
+use o2o::traits::{FromWith, IntoWith};
+
+struct Locale {
+    decimal_separator: char,
+}
+
+struct Clock {
+    now: u64,
+}
+
+struct Entity {
+    price: f64,
+    created_at: u64,
+}
+
+#[derive(o2o)]
+#[from(Entity| with(locale: &Locale))]
+#[owned_into(Entity| with(clock: &Clock))]
+#[ghosts(created_at: { clock.now })]
+struct EntityDto {
+    #[from(~.to_string().replace('.', &locale.decimal_separator.to_string()))]
+    #[into(~.replace(',', ".").parse().unwrap())]
+    price: String,
+}
+
+#[derive(o2o)]
+#[from_owned(Entity| with(multiplier: u64), fn(from_entity))]
+struct MultipliedDto {
+    #[from(created_at, ~ * multiplier)]
+    value: u64,
+}
+
+#[test]
+fn from_with() {
+    let locale = Locale { decimal_separator: ',' };
+    let entity = Entity { price: 1.5, created_at: 123 };
+
+    let dto = EntityDto::from_with(&entity, &locale);
+    assert_eq!("1,5", dto.price);
+
+    let dto: EntityDto = FromWith::from_with(entity, &locale);
+    assert_eq!("1,5", dto.price);
+}
+
+#[test]
+fn into_with() {
+    let clock = Clock { now: 456 };
+    let dto = EntityDto { price: "2,5".into() };
+
+    let entity: Entity = dto.into_with(&clock);
+    assert_eq!(2.5, entity.price);
+    assert_eq!(456, entity.created_at);
+}
+
+#[test]
+fn standalone_fn_with() {
+    let entity = Entity { price: 1.5, created_at: 123 };
+
+    let dto = MultipliedDto::from_entity(entity, 2);
+    assert_eq!(246, dto.value);
+}
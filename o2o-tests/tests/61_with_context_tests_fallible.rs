@@ -0,0 +1,67 @@
+use o2o::o2o;
+use o2o::traits::{TryFromWith, TryIntoWith};
+
+struct Limits {
+    max_price: f64,
+}
+
+struct Clock {
+    now: u64,
+}
+
+struct Entity {
+    price: f64,
+    created_at: u64,
+}
+
+#[derive(Debug, o2o)]
+#[try_from(Entity, String| with(limits: &Limits))]
+#[owned_try_into(Entity, String| with(clock: &Clock))]
+#[ghosts(created_at: { clock.now })]
+struct EntityDto {
+    #[from(if ~ > limits.max_price { Err("too expensive".to_string())? } else { ~.to_string() })]
+    #[into(~.parse().map_err(|_| "not a number".to_string())?)]
+    price: String,
+}
+
+#[derive(Debug, o2o)]
+#[try_from_owned(Entity, String| with(multiplier: u64), fn(from_entity))]
+struct MultipliedDto {
+    #[from(created_at, ~.checked_mul(multiplier).ok_or("overflow".to_string())?)]
+    value: u64,
+}
+
+#[test]
+fn try_from_with() {
+    let limits = Limits { max_price: 10.0 };
+
+    let dto = EntityDto::try_from_with(&Entity { price: 1.5, created_at: 123 }, &limits).unwrap();
+    assert_eq!("1.5", dto.price);
+
+    let dto: EntityDto = TryFromWith::try_from_with(Entity { price: 2.5, created_at: 123 }, &limits).unwrap();
+    assert_eq!("2.5", dto.price);
+
+    let err = EntityDto::try_from_with(Entity { price: 11.0, created_at: 123 }, &limits).unwrap_err();
+    assert_eq!("too expensive", err);
+}
+
+#[test]
+fn try_into_with() {
+    let clock = Clock { now: 456 };
+
+    let entity: Entity = EntityDto { price: "2.5".into() }.try_into_with(&clock).unwrap();
+    assert_eq!(2.5, entity.price);
+    assert_eq!(456, entity.created_at);
+
+    let result: Result<Entity, String> = EntityDto { price: "abc".into() }.try_into_with(&clock);
+    assert_eq!(Some("not a number".to_string()), result.err());
+}
+
+#[test]
+fn standalone_fn_with() {
+    let dto = MultipliedDto::from_entity(Entity { price: 1.5, created_at: 123 }, 2).unwrap();
+    assert_eq!(246, dto.value);
+
+    let err = MultipliedDto::from_entity(Entity { price: 1.5, created_at: u64::MAX }, 2).unwrap_err();
+    assert_eq!("overflow", err);
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use o2o::o2o;
+use o2o::traits::{AsyncTryFrom, AsyncTryInto};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+struct Repository {
+    users: HashMap<i32, String>,
+}
+
+impl Repository {
+    async fn find_user(&self, id: i32) -> Result<String, String> {
+        self.users.get(&id).cloned().ok_or(format!("user {} not found", id))
+    }
+
+    async fn find_id(&self, name: &str) -> Result<i32, String> {
+        self.users.iter().find(|(_, x)| *x == name).map(|(id, _)| *id).ok_or(format!("user '{}' not found", name))
+    }
+}
+
+fn repository() -> &'static Repository {
+    static REPOSITORY: std::sync::OnceLock<Repository> = std::sync::OnceLock::new();
+    REPOSITORY.get_or_init(|| Repository { users: HashMap::from([(1, "John".into()), (2, "Jane".into())]) })
+}
+
+#[derive(Default)]
+struct Order {
+    id: i32,
+    user_id: i32,
+    child: Child,
+}
+
+#[derive(Default)]
+struct Child {
+    child_int: i32,
+}
+
+#[derive(Debug, o2o)]
+#[async_try_from(Order, String)]
+#[async_try_into(Order, String)]
+#[child_parents(child: Child)]
+struct OrderDto {
+    id: i32,
+    #[from(user_id, repository().find_user(~).await?)]
+    #[into(user_id, repository().find_id(&~).await?)]
+    user_name: String,
+    #[child(child)]
+    child_int: i32,
+}
+
+#[derive(Debug, o2o)]
+#[o2o(async_try_from_owned(Order, String| fn(from_order)))]
+#[o2o(async_owned_try_into(Order, String| ..Default::default()))]
+struct PartialOrderDto {
+    #[from(user_id, repository().find_user(~).await?)]
+    #[into(user_id, repository().find_id(&~).await?)]
+    user_name: String,
+}
+
+#[derive(Debug, o2o)]
+#[async_try_from_ref(Order, String)]
+#[async_ref_try_into(Order, String| ..Default::default())]
+struct RefOrderDto {
+    #[from(user_id, repository().find_user(~).await?)]
+    #[into(user_id, repository().find_id(&~).await?)]
+    user_name: String,
+}
+
+#[test]
+fn async_try_from() {
+    let order = Order { id: 123, user_id: 2, child: Child { child_int: 456 } };
+
+    let dto = block_on(OrderDto::try_from_async(&order)).unwrap();
+    assert_eq!(123, dto.id);
+    assert_eq!("Jane", dto.user_name);
+    assert_eq!(456, dto.child_int);
+
+    let dto = block_on(OrderDto::try_from_async(order)).unwrap();
+    assert_eq!("Jane", dto.user_name);
+
+    let order = Order { id: 123, user_id: 3, child: Child { child_int: 456 } };
+    let err = block_on(OrderDto::try_from_async(order)).unwrap_err();
+    assert_eq!("user 3 not found", err);
+}
+
+#[test]
+fn async_try_into() {
+    let dto = OrderDto { id: 123, user_name: "John".into(), child_int: 456 };
+
+    let order: Order = block_on((&dto).try_into_async()).unwrap();
+    assert_eq!(123, order.id);
+    assert_eq!(1, order.user_id);
+    assert_eq!(456, order.child.child_int);
+
+    let order: Order = block_on(dto.try_into_async()).unwrap();
+    assert_eq!(1, order.user_id);
+
+    let dto = OrderDto { id: 123, user_name: "Joe".into(), child_int: 456 };
+    let result: Result<Order, String> = block_on(dto.try_into_async());
+    assert_eq!(Some("user 'Joe' not found".to_string()), result.err());
+}
+
+#[test]
+fn async_standalone_fn() {
+    let order = Order { id: 123, user_id: 1, child: Child { child_int: 456 } };
+
+    let dto = block_on(PartialOrderDto::from_order(order)).unwrap();
+    assert_eq!("John", dto.user_name);
+
+    let order: Order = block_on(dto.try_into_async()).unwrap();
+    assert_eq!(0, order.id);
+    assert_eq!(1, order.user_id);
+}
+
+#[test]
+fn async_ref_instructions() {
+    let order = Order { id: 123, user_id: 2, child: Child { child_int: 456 } };
+
+    let dto = block_on(RefOrderDto::try_from_async(&order)).unwrap();
+    assert_eq!("Jane", dto.user_name);
+
+    let order: Order = block_on((&dto).try_into_async()).unwrap();
+    assert_eq!(0, order.id);
+    assert_eq!(2, order.user_id);
+}
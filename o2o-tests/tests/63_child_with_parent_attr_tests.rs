@@ -0,0 +1,134 @@
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Default)]
+struct Car {
+    number_of_doors: i8,
+    vehicle: Vehicle,
+    price: i32,
+}
+
+#[derive(Default)]
+struct Vehicle {
+    number_of_seats: i16,
+    machine: Machine,
+}
+
+#[derive(Default)]
+struct Machine {
+    brand: String,
+    year: i16,
+}
+
+#[derive(o2o)]
+#[from(Car)]
+#[into_existing(Car)]
+struct Price {
+    price: i32,
+}
+
+#[derive(o2o)]
+#[from_ref(Car)]
+#[owned_into(Car)]
+struct CarDto {
+    number_of_doors: i8,
+
+    #[child(vehicle)]
+    number_of_seats: i16,
+
+    #[child(vehicle.machine)]
+    #[from(~.clone())]
+    brand: String,
+
+    #[child(vehicle.machine)]
+    #[map(year)]
+    manufactured: i16,
+
+    #[parent]
+    price: Price,
+}
+
+#[derive(o2o)]
+#[ref_into(Car)]
+#[ghosts(vehicle.machine@brand: { "Unknown".into() })]
+struct CarSummaryDto {
+    #[child(vehicle)]
+    number_of_seats: i16,
+
+    #[parent]
+    price: Price,
+}
+
+#[derive(o2o)]
+#[owned_into(Car| default_init)]
+struct MachineDto {
+    #[child(vehicle.machine)]
+    brand: String,
+
+    #[child(vehicle.machine)]
+    year: i16,
+}
+
+#[test]
+fn owned_into() {
+    let dto = CarDto {
+        number_of_doors: 2,
+        number_of_seats: 4,
+        brand: "Trabant".into(),
+        manufactured: 1957,
+        price: Price { price: 1000 },
+    };
+
+    let car: Car = dto.into();
+
+    assert_eq!(2, car.number_of_doors);
+    assert_eq!(4, car.vehicle.number_of_seats);
+    assert_eq!("Trabant", car.vehicle.machine.brand);
+    assert_eq!(1957, car.vehicle.machine.year);
+    assert_eq!(1000, car.price);
+}
+
+#[test]
+fn from_ref() {
+    let car = Car {
+        number_of_doors: 2,
+        vehicle: Vehicle {
+            number_of_seats: 4,
+            machine: Machine { brand: "Trabant".into(), year: 1957 },
+        },
+        price: 1000,
+    };
+
+    let dto: CarDto = (&car).into();
+
+    assert_eq!(2, dto.number_of_doors);
+    assert_eq!(4, dto.number_of_seats);
+    assert_eq!("Trabant", dto.brand);
+    assert_eq!(1957, dto.manufactured);
+    assert_eq!(1000, dto.price.price);
+}
+
+#[test]
+fn ref_into_with_ghost() {
+    let dto = CarSummaryDto { number_of_seats: 4, price: Price { price: 1000 } };
+
+    let car: Car = (&dto).into();
+
+    assert_eq!(0, car.number_of_doors);
+    assert_eq!(4, car.vehicle.number_of_seats);
+    assert_eq!("Unknown", car.vehicle.machine.brand);
+    assert_eq!(0, car.vehicle.machine.year);
+    assert_eq!(1000, car.price);
+}
+
+#[test]
+fn owned_into_default_init() {
+    let dto = MachineDto { brand: "Trabant".into(), year: 1957 };
+
+    let car: Car = dto.into();
+
+    assert_eq!(0, car.number_of_doors);
+    assert_eq!(0, car.vehicle.number_of_seats);
+    assert_eq!("Trabant", car.vehicle.machine.brand);
+    assert_eq!(1957, car.vehicle.machine.year);
+}
@@ -0,0 +1,86 @@
+use o2o::o2o;
+use o2o::traits::{DiffInto, FieldChange, IntoExisting};
+
+#[derive(Default)]
+struct Person {
+    id: i32,
+    full_name: String,
+    age: i16,
+    address: Address,
+    updated_by: String,
+}
+
+#[derive(Default)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(o2o)]
+#[ref_into_existing(Person| diff)]
+#[owned_into_existing(Person| diff)]
+#[ghosts(updated_by: { "admin".to_string() })]
+struct PersonDto {
+    id: i32,
+    #[into(full_name, ~.clone())]
+    name: String,
+    #[into(~ as i16)]
+    age: u8,
+    #[child(address)]
+    #[into(~.clone())]
+    city: String,
+    #[parent]
+    zip: ZipDto,
+}
+
+#[derive(o2o)]
+#[ref_into_existing(Person| diff)]
+#[owned_into_existing(Person| diff)]
+struct ZipDto {
+    #[child(address)]
+    #[into(~.clone())]
+    zip: String,
+}
+
+fn person() -> Person {
+    Person {
+        id: 123,
+        full_name: "John Doe".into(),
+        age: 42,
+        address: Address { city: "Berlin".into(), zip: "10115".into() },
+        updated_by: "admin".into(),
+    }
+}
+
+#[test]
+fn ref_diff_into() {
+    let person = person();
+    let dto = PersonDto { id: 123, name: "John Doe".into(), age: 43, city: "Paris".into(), zip: ZipDto { zip: "75001".into() } };
+
+    let changes = (&dto).diff_into(&person);
+
+    assert_eq!(vec![FieldChange::new("age"), FieldChange::new("address.city"), FieldChange::new("address.zip")], changes);
+}
+
+#[test]
+fn owned_diff_into() {
+    let mut person = person();
+    person.updated_by = "system".into();
+    let dto = PersonDto { id: 321, name: "Jane Doe".into(), age: 42, city: "Berlin".into(), zip: ZipDto { zip: "10115".into() } };
+
+    let changes = dto.diff_into(&person);
+
+    assert_eq!(vec!["id", "full_name", "updated_by"], changes.iter().map(|x| x.path).collect::<Vec<_>>());
+}
+
+#[test]
+fn no_changes_after_into_existing() {
+    let mut person = Person::default();
+    let dto = PersonDto { id: 321, name: "Jane Doe".into(), age: 42, city: "Berlin".into(), zip: ZipDto { zip: "10115".into() } };
+
+    assert_eq!(6, (&dto).diff_into(&person).len());
+
+    (&dto).into_existing(&mut person);
+
+    assert!((&dto).diff_into(&person).is_empty());
+}
@@ -0,0 +1,135 @@
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Default)]
+struct Person {
+    id: i32,
+    name: String,
+    age: i16,
+    address: Address,
+}
+
+#[derive(Default)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(o2o)]
+#[owned_into_existing(Person| patch)]
+#[ref_into_existing(Person| patch)]
+struct PersonPatch {
+    name: Option<String>,
+    #[into(~.map(|x| x as i16))]
+    age: Option<u8>,
+    #[child(address)]
+    city: Option<String>,
+}
+
+#[derive(o2o)]
+#[owned_into_existing(Person)]
+#[ref_into_existing(Person)]
+struct PersonUpdate {
+    id: i32,
+    #[into_existing(skip_if = Option::is_none, ~.clone().unwrap())]
+    name: Option<String>,
+    #[child(address)]
+    #[into_existing(skip_if = String::is_empty, ~.clone())]
+    zip: String,
+}
+
+#[derive(o2o)]
+#[owned_into_existing(Person| patch)]
+struct PersonMixedPatch {
+    #[into_existing(patch = false)]
+    id: i32,
+    #[into_existing(skip_if = Option::is_none, ~.unwrap())]
+    name: Option<String>,
+    #[into(~.map(|x| x as i16))]
+    age: Option<u8>,
+}
+
+fn person() -> Person {
+    Person {
+        id: 123,
+        name: "John".into(),
+        age: 42,
+        address: Address { city: "Berlin".into(), zip: "10115".into() },
+    }
+}
+
+#[test]
+fn owned_patch() {
+    let mut person = person();
+
+    PersonPatch { name: Some("Jane".into()), age: None, city: None }.into_existing(&mut person);
+
+    assert_eq!(123, person.id);
+    assert_eq!("Jane", person.name);
+    assert_eq!(42, person.age);
+    assert_eq!("Berlin", person.address.city);
+
+    PersonPatch { name: None, age: Some(43), city: Some("Paris".into()) }.into_existing(&mut person);
+
+    assert_eq!("Jane", person.name);
+    assert_eq!(43, person.age);
+    assert_eq!("Paris", person.address.city);
+}
+
+#[test]
+fn ref_patch() {
+    let mut person = person();
+    let patch = PersonPatch { name: None, age: Some(18), city: Some("Paris".into()) };
+
+    (&patch).into_existing(&mut person);
+
+    assert_eq!("John", person.name);
+    assert_eq!(18, person.age);
+    assert_eq!("Paris", person.address.city);
+    assert_eq!("10115", person.address.zip);
+}
+
+#[test]
+fn owned_skip_if() {
+    let mut person = person();
+
+    PersonUpdate { id: 321, name: None, zip: "".into() }.into_existing(&mut person);
+
+    assert_eq!(321, person.id);
+    assert_eq!("John", person.name);
+    assert_eq!("10115", person.address.zip);
+
+    PersonUpdate { id: 321, name: Some("Jane".into()), zip: "75001".into() }.into_existing(&mut person);
+
+    assert_eq!("Jane", person.name);
+    assert_eq!("75001", person.address.zip);
+}
+
+#[test]
+fn ref_skip_if() {
+    let mut person = person();
+    let update = PersonUpdate { id: 321, name: Some("Jane".into()), zip: "".into() };
+
+    (&update).into_existing(&mut person);
+
+    assert_eq!(321, person.id);
+    assert_eq!("Jane", person.name);
+    assert_eq!("10115", person.address.zip);
+}
+
+#[test]
+fn patch_with_skip_if_and_opt_out() {
+    let mut person = person();
+
+    PersonMixedPatch { id: 321, name: None, age: Some(43) }.into_existing(&mut person);
+
+    assert_eq!(321, person.id);
+    assert_eq!("John", person.name);
+    assert_eq!(43, person.age);
+
+    PersonMixedPatch { id: 456, name: Some("Jane".into()), age: None }.into_existing(&mut person);
+
+    assert_eq!(456, person.id);
+    assert_eq!("Jane", person.name);
+    assert_eq!(43, person.age);
+}
@@ -0,0 +1,118 @@
+use o2o::o2o;
+use o2o::traits::TryIntoExisting;
+
+#[derive(Default)]
+struct Person {
+    id: i32,
+    name: String,
+    age: i16,
+    address: Address,
+}
+
+#[derive(Default)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+#[derive(o2o)]
+#[owned_try_into_existing(Person, String| patch)]
+#[ref_try_into_existing(Person, String| patch)]
+struct PersonPatch {
+    name: Option<String>,
+    #[into(~.map(|x| i16::try_from(x).map_err(|e| e.to_string())).transpose()?)]
+    age: Option<u32>,
+    #[child(address)]
+    city: Option<String>,
+}
+
+#[derive(o2o)]
+#[owned_try_into_existing(Person, String)]
+#[ref_try_into_existing(Person, String)]
+struct PersonUpdate {
+    id: i32,
+    #[into_existing(skip_if = Option::is_none, ~.clone().unwrap())]
+    name: Option<String>,
+    #[child(address)]
+    #[into_existing(skip_if = String::is_empty, ~.clone())]
+    zip: String,
+}
+
+fn person() -> Person {
+    Person {
+        id: 123,
+        name: "John".into(),
+        age: 42,
+        address: Address { city: "Berlin".into(), zip: "10115".into() },
+    }
+}
+
+#[test]
+fn owned_try_patch() {
+    let mut person = person();
+
+    PersonPatch { name: Some("Jane".into()), age: None, city: None }.try_into_existing(&mut person).unwrap();
+
+    assert_eq!(123, person.id);
+    assert_eq!("Jane", person.name);
+    assert_eq!(42, person.age);
+    assert_eq!("Berlin", person.address.city);
+
+    PersonPatch { name: None, age: Some(43), city: Some("Paris".into()) }.try_into_existing(&mut person).unwrap();
+
+    assert_eq!("Jane", person.name);
+    assert_eq!(43, person.age);
+    assert_eq!("Paris", person.address.city);
+}
+
+#[test]
+fn ref_try_patch() {
+    let mut person = person();
+    let patch = PersonPatch { name: None, age: Some(18), city: Some("Paris".into()) };
+
+    (&patch).try_into_existing(&mut person).unwrap();
+
+    assert_eq!("John", person.name);
+    assert_eq!(18, person.age);
+    assert_eq!("Paris", person.address.city);
+    assert_eq!("10115", person.address.zip);
+}
+
+#[test]
+fn owned_try_skip_if() {
+    let mut person = person();
+
+    PersonUpdate { id: 321, name: None, zip: "".into() }.try_into_existing(&mut person).unwrap();
+
+    assert_eq!(321, person.id);
+    assert_eq!("John", person.name);
+    assert_eq!("10115", person.address.zip);
+
+    PersonUpdate { id: 321, name: Some("Jane".into()), zip: "75001".into() }.try_into_existing(&mut person).unwrap();
+
+    assert_eq!("Jane", person.name);
+    assert_eq!("75001", person.address.zip);
+}
+
+#[test]
+fn ref_try_skip_if() {
+    let mut person = person();
+    let update = PersonUpdate { id: 321, name: Some("Jane".into()), zip: "".into() };
+
+    (&update).try_into_existing(&mut person).unwrap();
+
+    assert_eq!(321, person.id);
+    assert_eq!("Jane", person.name);
+    assert_eq!("10115", person.address.zip);
+}
+
+#[test]
+fn try_patch_error() {
+    let mut person = person();
+
+    let result = PersonPatch { name: Some("Jane".into()), age: Some(100_000), city: None }.try_into_existing(&mut person);
+
+    assert_eq!(Err("out of range integral type conversion attempted".to_string()), result);
+    assert_eq!("Jane", person.name);
+    assert_eq!(42, person.age);
+}
@@ -0,0 +1,100 @@
+use o2o::o2o;
+use o2o::traits::{DiffInto, FieldChange, IntoExisting};
+
+#[derive(Default)]
+struct Person {
+    id: i32,
+    address: Address,
+    company: Company,
+}
+
+#[derive(Default)]
+struct Company {
+    name: String,
+    address: Address,
+}
+
+#[derive(Default)]
+struct Address {
+    city: String,
+    zip: String,
+    verified: bool,
+}
+
+#[derive(o2o)]
+#[owned_into_existing(Address| diff)]
+#[ref_into_existing(Address| diff)]
+struct AddressDto {
+    #[into(~.clone())]
+    city: String,
+    #[into(~.clone())]
+    zip: String,
+}
+
+#[derive(o2o)]
+#[owned_into_existing(Person| diff)]
+#[ref_into_existing(Person| diff)]
+struct PersonDto {
+    id: i32,
+    #[into_existing(nested = true)]
+    address: AddressDto,
+    #[child(company)]
+    #[into_existing(address, nested = true)]
+    company_address: Option<AddressDto>,
+}
+
+fn person() -> Person {
+    Person {
+        id: 123,
+        address: Address { city: "Berlin".into(), zip: "10115".into(), verified: true },
+        company: Company { name: "ACME".into(), address: Address { city: "Paris".into(), zip: "75001".into(), verified: true } },
+    }
+}
+
+#[test]
+fn owned_nested_into_existing() {
+    let mut person = person();
+
+    PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "80331".into() },
+        company_address: None,
+    }.into_existing(&mut person);
+
+    assert_eq!(321, person.id);
+    assert_eq!("Munich", person.address.city);
+    assert_eq!("80331", person.address.zip);
+    assert!(person.address.verified);
+    assert_eq!("ACME", person.company.name);
+    assert_eq!("Paris", person.company.address.city);
+}
+
+#[test]
+fn ref_nested_into_existing() {
+    let mut person = person();
+    let dto = PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "80331".into() },
+        company_address: Some(AddressDto { city: "Lyon".into(), zip: "69001".into() }),
+    };
+
+    (&dto).into_existing(&mut person);
+
+    assert_eq!("Munich", person.address.city);
+    assert!(person.address.verified);
+    assert_eq!("Lyon", person.company.address.city);
+    assert_eq!("69001", person.company.address.zip);
+    assert!(person.company.address.verified);
+}
+
+#[test]
+fn nested_diff_into() {
+    let person = person();
+    let dto = PersonDto {
+        id: 123,
+        address: AddressDto { city: "Berlin".into(), zip: "10115".into() },
+        company_address: Some(AddressDto { city: "Lyon".into(), zip: "75001".into() }),
+    };
+
+    assert_eq!(vec![FieldChange::new("company.address")], (&dto).diff_into(&person));
+}
@@ -0,0 +1,108 @@
+use o2o::o2o;
+use o2o::traits::TryIntoExisting;
+
+#[derive(Default)]
+struct Person {
+    id: i32,
+    address: Address,
+    company: Company,
+}
+
+#[derive(Default)]
+struct Company {
+    name: String,
+    address: Address,
+}
+
+#[derive(Default)]
+struct Address {
+    city: String,
+    zip: i32,
+    verified: bool,
+}
+
+#[derive(o2o)]
+#[owned_try_into_existing(Address, String)]
+#[ref_try_into_existing(Address, String)]
+struct AddressDto {
+    #[into(~.clone())]
+    city: String,
+    #[into(~.parse().map_err(|_| format!("Invalid zip '{}'", ~))?)]
+    zip: String,
+}
+
+#[derive(o2o)]
+#[owned_try_into_existing(Person, String)]
+#[ref_try_into_existing(Person, String)]
+struct PersonDto {
+    id: i32,
+    #[into_existing(nested = true)]
+    address: AddressDto,
+    #[child(company)]
+    #[into_existing(address, nested = true, map_err = |e| format!("company: {}", e))]
+    company_address: Option<AddressDto>,
+}
+
+fn person() -> Person {
+    Person {
+        id: 123,
+        address: Address { city: "Berlin".into(), zip: 10115, verified: true },
+        company: Company { name: "ACME".into(), address: Address { city: "Paris".into(), zip: 75001, verified: true } },
+    }
+}
+
+#[test]
+fn owned_nested_try_into_existing() {
+    let mut person = person();
+
+    PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "80331".into() },
+        company_address: None,
+    }.try_into_existing(&mut person).unwrap();
+
+    assert_eq!(321, person.id);
+    assert_eq!("Munich", person.address.city);
+    assert_eq!(80331, person.address.zip);
+    assert!(person.address.verified);
+    assert_eq!("ACME", person.company.name);
+    assert_eq!("Paris", person.company.address.city);
+}
+
+#[test]
+fn ref_nested_try_into_existing() {
+    let mut person = person();
+    let dto = PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "80331".into() },
+        company_address: Some(AddressDto { city: "Lyon".into(), zip: "69001".into() }),
+    };
+
+    (&dto).try_into_existing(&mut person).unwrap();
+
+    assert_eq!("Munich", person.address.city);
+    assert_eq!("Lyon", person.company.address.city);
+    assert_eq!(69001, person.company.address.zip);
+    assert!(person.company.address.verified);
+}
+
+#[test]
+fn nested_try_into_existing_error() {
+    let mut person = person();
+
+    let result = PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "abc".into() },
+        company_address: None,
+    }.try_into_existing(&mut person);
+
+    assert_eq!(Err("Invalid zip 'abc'".to_string()), result);
+
+    let result = PersonDto {
+        id: 321,
+        address: AddressDto { city: "Munich".into(), zip: "80331".into() },
+        company_address: Some(AddressDto { city: "Lyon".into(), zip: "xyz".into() }),
+    }.try_into_existing(&mut person);
+
+    assert_eq!(Err("company: Invalid zip 'xyz'".to_string()), result);
+}
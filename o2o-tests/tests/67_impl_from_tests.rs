@@ -0,0 +1,83 @@
+use o2o::o2o;
+
+#[derive(Default)]
+struct Entity {
+    some_int: i32,
+    another_int: i16,
+}
+
+#[derive(PartialEq, Debug)]
+enum EnumModel {
+    Item1,
+    Item2(i32),
+}
+
+#[derive(o2o)]
+#[owned_into(Entity| impl_from)]
+#[ref_into(Entity| impl_from)]
+struct EntityDto {
+    some_int: i32,
+    #[into(~ as i16)]
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[owned_into(EnumModel| impl_from)]
+enum EnumDto {
+    Item1,
+    Item2(i32),
+}
+
+#[derive(o2o)]
+#[owned_into(Wrapper<T>| impl_from)]
+struct GenericDto<T> {
+    value: T,
+}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+#[test]
+fn owned_from() {
+    let dto = EntityDto { some_int: 123, another_int: 456 };
+
+    let entity = Entity::from(dto);
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(456, entity.another_int);
+}
+
+#[test]
+fn ref_from() {
+    let dto = EntityDto { some_int: 123, another_int: 456 };
+
+    let entity = Entity::from(&dto);
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(456, entity.another_int);
+}
+
+#[test]
+fn into_still_works() {
+    let dto = EntityDto { some_int: 123, another_int: 456 };
+
+    let entity: Entity = (&dto).into();
+    assert_eq!(123, entity.some_int);
+
+    let entity: Entity = dto.into();
+    assert_eq!(456, entity.another_int);
+}
+
+#[test]
+fn enum_from() {
+    assert_eq!(EnumModel::Item1, EnumModel::from(EnumDto::Item1));
+    assert_eq!(EnumModel::Item2(123), EnumModel::from(EnumDto::Item2(123)));
+}
+
+#[test]
+fn generic_from() {
+    let wrapper = Wrapper::from(GenericDto { value: 123 });
+
+    assert_eq!(123, wrapper.value);
+}
@@ -0,0 +1,65 @@
+use o2o::o2o;
+
+#[derive(Default)]
+struct Entity {
+    some_int: i32,
+    another_int: i16,
+}
+
+#[derive(PartialEq, Debug)]
+enum EnumModel {
+    Item1,
+    Item2(i32),
+}
+
+#[derive(o2o)]
+#[owned_try_into(Entity, String| impl_from)]
+#[ref_try_into(Entity, String| impl_from)]
+struct EntityDto {
+    some_int: i32,
+    #[into(~.try_into().map_err(|_| "another_int is out of range".to_string())?)]
+    another_int: i32,
+}
+
+#[derive(o2o)]
+#[owned_try_into(EnumModel, String| impl_from)]
+enum EnumDto {
+    Item1,
+    Item2(i32),
+}
+
+#[test]
+fn owned_try_from() {
+    let dto = EntityDto { some_int: 123, another_int: 456 };
+
+    let entity = Entity::try_from(dto).unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(456, entity.another_int);
+}
+
+#[test]
+fn ref_try_from() {
+    let dto = EntityDto { some_int: 123, another_int: 456 };
+
+    let entity = Entity::try_from(&dto).unwrap();
+
+    assert_eq!(123, entity.some_int);
+    assert_eq!(456, entity.another_int);
+}
+
+#[test]
+fn try_from_error() {
+    let dto = EntityDto { some_int: 123, another_int: 100_000 };
+
+    assert_eq!(Some("another_int is out of range".to_string()), Entity::try_from(&dto).err());
+
+    let result: Result<Entity, String> = dto.try_into();
+    assert_eq!(Some("another_int is out of range".to_string()), result.err());
+}
+
+#[test]
+fn enum_try_from() {
+    assert_eq!(Ok(EnumModel::Item1), EnumModel::try_from(EnumDto::Item1));
+    assert_eq!(Ok(EnumModel::Item2(123)), EnumModel::try_from(EnumDto::Item2(123)));
+}
@@ -0,0 +1,90 @@
+use o2o::o2o;
+
+#[derive(Debug, Default, PartialEq)]
+struct WireEvent {
+    kind: String,
+    id: Option<i32>,
+    name: Option<String>,
+    amount: Option<f32>,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[owned_into(WireEvent| tag(kind))]
+#[ref_into(WireEvent| tag(kind))]
+enum Event {
+    #[literal("created")]
+    Created {
+        #[ref_into(*~)]
+        id: i32,
+        #[ref_into(~.clone())]
+        name: String,
+    },
+    #[literal("paid")]
+    Paid {
+        #[ref_into(*~)]
+        id: i32,
+        #[ref_into(*~)]
+        amount: f32,
+    },
+    #[literal("closed")]
+    Closed,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct WireShape {
+    tag: u8,
+    size: Option<f32>,
+    label: Option<String>,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[owned_into(WireShape| tag(tag))]
+enum Shape {
+    #[literal(1)]
+    Circle {
+        #[map(size)]
+        radius: f32,
+    },
+    #[literal(2)]
+    Square {
+        size: f32,
+        label: String,
+    },
+    #[literal(0)]
+    Unknown,
+}
+
+#[test]
+fn enum_to_struct() {
+    let wire: WireEvent = Event::Created { id: 1, name: "John".into() }.into();
+    assert_eq!(WireEvent { kind: "created".into(), id: Some(1), name: Some("John".into()), amount: None }, wire);
+
+    let wire: WireEvent = Event::Paid { id: 2, amount: 9.5 }.into();
+    assert_eq!(WireEvent { kind: "paid".into(), id: Some(2), name: None, amount: Some(9.5) }, wire);
+
+    let wire: WireEvent = Event::Closed.into();
+    assert_eq!(WireEvent { kind: "closed".into(), ..Default::default() }, wire);
+}
+
+#[test]
+fn ref_enum_to_struct() {
+    let event = Event::Created { id: 1, name: "John".into() };
+    let wire: WireEvent = (&event).into();
+    assert_eq!(WireEvent { kind: "created".into(), id: Some(1), name: Some("John".into()), amount: None }, wire);
+
+    let event = Event::Paid { id: 2, amount: 9.5 };
+    let wire: WireEvent = (&event).into();
+    assert_eq!(WireEvent { kind: "paid".into(), id: Some(2), name: None, amount: Some(9.5) }, wire);
+}
+
+#[test]
+fn integer_tag() {
+    let wire: WireShape = Shape::Circle { radius: 2.0 }.into();
+    assert_eq!(WireShape { tag: 1, size: Some(2.0), label: None }, wire);
+
+    let wire: WireShape = Shape::Square { size: 3.0, label: "box".into() }.into();
+    assert_eq!(WireShape { tag: 2, size: Some(3.0), label: Some("box".into()) }, wire);
+
+    let wire: WireShape = Shape::Unknown.into();
+    assert_eq!(WireShape { tag: 0, ..Default::default() }, wire);
+}
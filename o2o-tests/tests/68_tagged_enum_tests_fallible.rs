@@ -0,0 +1,101 @@
+use o2o::o2o;
+use o2o::traits::MissingFieldError;
+
+#[derive(Debug, Default, PartialEq)]
+struct WireEvent {
+    kind: String,
+    id: Option<i32>,
+    name: Option<String>,
+    amount: Option<f32>,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[owned_try_into(WireEvent, String| tag(kind))]
+#[try_from_owned(WireEvent, String| tag(kind), _ => Err(format!("unknown kind '{}'", value.kind))?)]
+#[try_from_ref(WireEvent, String| tag(kind), _ => Err(format!("unknown kind '{}'", value.kind))?)]
+enum Event {
+    #[literal("created")]
+    Created {
+        #[from(~.ok_or("missing id")?)]
+        id: i32,
+        #[from_owned(~.ok_or("missing name")?)]
+        #[from_ref(~.clone().ok_or("missing name")?)]
+        name: String,
+    },
+    #[literal("paid")]
+    Paid {
+        #[from(~.ok_or("missing id")?)]
+        id: i32,
+        #[from(~.ok_or("missing amount")?)]
+        amount: f32,
+    },
+    #[literal("closed")]
+    Closed,
+}
+
+#[derive(Debug, PartialEq)]
+enum WireError {
+    MissingField(&'static str),
+    UnknownKind(String),
+}
+
+impl From<MissingFieldError> for WireError {
+    fn from(value: MissingFieldError) -> Self {
+        WireError::MissingField(value.0)
+    }
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[try_from_owned(WireEvent, WireError| tag(kind), _ => Err(WireError::UnknownKind(value.kind))?)]
+enum Payment {
+    #[literal("paid")]
+    Paid { id: i32, amount: f32 },
+    #[literal("closed")]
+    Closed,
+}
+
+#[test]
+fn enum_to_struct() {
+    let wire: WireEvent = Event::Created { id: 1, name: "John".into() }.try_into().unwrap();
+    assert_eq!(WireEvent { kind: "created".into(), id: Some(1), name: Some("John".into()), amount: None }, wire);
+
+    let wire: WireEvent = Event::Closed.try_into().unwrap();
+    assert_eq!(WireEvent { kind: "closed".into(), ..Default::default() }, wire);
+}
+
+#[test]
+fn struct_to_enum() {
+    let event: Event = WireEvent { kind: "paid".into(), id: Some(2), name: None, amount: Some(9.5) }.try_into().unwrap();
+    assert_eq!(Event::Paid { id: 2, amount: 9.5 }, event);
+
+    let wire = WireEvent { kind: "created".into(), id: Some(1), name: Some("John".into()), amount: None };
+    let event = Event::try_from(&wire).unwrap();
+    assert_eq!(Event::Created { id: 1, name: "John".into() }, event);
+}
+
+#[test]
+fn missing_payload() {
+    let result: Result<Event, String> = WireEvent { kind: "paid".into(), id: Some(2), ..Default::default() }.try_into();
+    assert_eq!(Err("missing amount".to_string()), result);
+
+    let wire = WireEvent { kind: "created".into(), id: Some(1), ..Default::default() };
+    assert_eq!(Err("missing name".to_string()), Event::try_from(&wire));
+}
+
+#[test]
+fn unknown_tag() {
+    let result: Result<Event, String> = WireEvent { kind: "deleted".into(), ..Default::default() }.try_into();
+    assert_eq!(Err("unknown kind 'deleted'".to_string()), result);
+}
+
+#[test]
+fn missing_field_error() {
+    let payment: Payment = WireEvent { kind: "paid".into(), id: Some(2), name: None, amount: Some(9.5) }.try_into().unwrap();
+    assert_eq!(Payment::Paid { id: 2, amount: 9.5 }, payment);
+
+    let result: Result<Payment, WireError> = WireEvent { kind: "paid".into(), id: Some(2), ..Default::default() }.try_into();
+    assert_eq!(Err(WireError::MissingField("amount")), result);
+
+    let result: Result<Payment, WireError> = WireEvent { kind: "sent".into(), ..Default::default() }.try_into();
+    assert_eq!(Err(WireError::UnknownKind("sent".into())), result);
+}
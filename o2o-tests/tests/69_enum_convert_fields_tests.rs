@@ -0,0 +1,116 @@
+use o2o::o2o;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entity {
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, o2o)]
+#[map(Entity)]
+struct EntityDto {
+    id: i32,
+    #[map_ref(~.clone())]
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Created(Entity),
+    Renamed { entity: Entity, old_name: String },
+    Deleted(i32),
+    Cleared,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[map(Event| convert_fields)]
+enum EventDto {
+    Created(EntityDto),
+    Renamed {
+        entity: EntityDto,
+        #[map_ref(~.clone())]
+        old_name: String,
+    },
+    Deleted(#[map_ref(*~)] i32),
+    Cleared,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[from_owned(Event| convert_fields)]
+enum EventSummary {
+    Created(EntityDto),
+    Renamed {
+        #[from(entity, ~.name)]
+        new_name: String,
+        old_name: String,
+    },
+    Deleted(i64),
+    Cleared,
+}
+
+fn entity() -> Entity {
+    Entity { id: 1, name: "John".into() }
+}
+
+fn entity_dto() -> EntityDto {
+    EntityDto { id: 1, name: "John".into() }
+}
+
+#[test]
+fn from() {
+    let dto: EventDto = Event::Created(entity()).into();
+    assert_eq!(EventDto::Created(entity_dto()), dto);
+
+    let dto: EventDto = Event::Renamed { entity: entity(), old_name: "Jack".into() }.into();
+    assert_eq!(EventDto::Renamed { entity: entity_dto(), old_name: "Jack".into() }, dto);
+
+    let dto: EventDto = Event::Deleted(1).into();
+    assert_eq!(EventDto::Deleted(1), dto);
+
+    let dto: EventDto = Event::Cleared.into();
+    assert_eq!(EventDto::Cleared, dto);
+}
+
+#[test]
+fn from_ref() {
+    let event = Event::Created(entity());
+    let dto: EventDto = (&event).into();
+    assert_eq!(EventDto::Created(entity_dto()), dto);
+
+    let event = Event::Renamed { entity: entity(), old_name: "Jack".into() };
+    let dto: EventDto = (&event).into();
+    assert_eq!(EventDto::Renamed { entity: entity_dto(), old_name: "Jack".into() }, dto);
+
+    let event = Event::Deleted(1);
+    let dto: EventDto = (&event).into();
+    assert_eq!(EventDto::Deleted(1), dto);
+}
+
+#[test]
+fn into() {
+    let event: Event = EventDto::Created(entity_dto()).into();
+    assert_eq!(Event::Created(entity()), event);
+
+    let event: Event = EventDto::Renamed { entity: entity_dto(), old_name: "Jack".into() }.into();
+    assert_eq!(Event::Renamed { entity: entity(), old_name: "Jack".into() }, event);
+}
+
+#[test]
+fn into_ref() {
+    let dto = EventDto::Created(entity_dto());
+    let event: Event = (&dto).into();
+    assert_eq!(Event::Created(entity()), event);
+
+    let dto = EventDto::Deleted(1);
+    let event: Event = (&dto).into();
+    assert_eq!(Event::Deleted(1), event);
+}
+
+#[test]
+fn different_payload_types() {
+    let summary: EventSummary = Event::Deleted(1).into();
+    assert_eq!(EventSummary::Deleted(1), summary);
+
+    let summary: EventSummary = Event::Renamed { entity: entity(), old_name: "Jack".into() }.into();
+    assert_eq!(EventSummary::Renamed { new_name: "John".into(), old_name: "Jack".into() }, summary);
+}
@@ -0,0 +1,89 @@
+use o2o::o2o;
+
+#[derive(Debug, PartialEq)]
+struct Entity {
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[try_map_owned(Entity, String)]
+struct EntityDto {
+    #[from(~.try_into().map_err(|_| "id is out of range")?)]
+    #[into(~.into())]
+    id: u8,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Name(String);
+
+impl From<String> for Name {
+    fn from(value: String) -> Self {
+        Name(value)
+    }
+}
+
+impl From<Name> for String {
+    fn from(value: Name) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Created(Entity),
+    Renamed { id: i32, name: String },
+    Deleted { id: i32 },
+    Cleared,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[try_map_owned(Event, String| convert_fields)]
+enum EventDto {
+    Created(EntityDto),
+    Renamed {
+        #[from(~)]
+        #[into(~)]
+        id: i32,
+        #[from(~.into())]
+        #[into(~.into())]
+        name: Name,
+    },
+    Deleted {
+        #[from(~.to_string())]
+        #[into(~.parse().map_err(|_| "id is not a number")?)]
+        id: String,
+    },
+    Cleared,
+}
+
+#[test]
+fn try_from() {
+    let dto: EventDto = Event::Created(Entity { id: 1, name: "John".into() }).try_into().unwrap();
+    assert_eq!(EventDto::Created(EntityDto { id: 1, name: "John".into() }), dto);
+
+    let dto: EventDto = Event::Renamed { id: 1, name: "Jane".into() }.try_into().unwrap();
+    assert_eq!(EventDto::Renamed { id: 1, name: Name("Jane".into()) }, dto);
+
+    let dto: EventDto = Event::Deleted { id: 1 }.try_into().unwrap();
+    assert_eq!(EventDto::Deleted { id: "1".into() }, dto);
+
+    let dto: Result<EventDto, String> = Event::Created(Entity { id: 1000, name: "John".into() }).try_into();
+    assert_eq!(Err("id is out of range".to_string()), dto);
+}
+
+#[test]
+fn try_into() {
+    let event: Event = EventDto::Created(EntityDto { id: 1, name: "John".into() }).try_into().unwrap();
+    assert_eq!(Event::Created(Entity { id: 1, name: "John".into() }), event);
+
+    let event: Event = EventDto::Renamed { id: 1, name: Name("Jane".into()) }.try_into().unwrap();
+    assert_eq!(Event::Renamed { id: 1, name: "Jane".into() }, event);
+
+    let event: Result<Event, String> = EventDto::Deleted { id: "one".into() }.try_into();
+    assert_eq!(Err("id is not a number".to_string()), event);
+
+    let event: Event = EventDto::Cleared.try_into().unwrap();
+    assert_eq!(Event::Cleared, event);
+}
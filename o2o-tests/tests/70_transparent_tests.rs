@@ -0,0 +1,104 @@
+use o2o::o2o;
+use o2o::traits::IntoExisting;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Uuid(u128);
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[map(Uuid)]
+#[into_existing(Uuid)]
+#[map(OrderId)]
+struct UserId(Uuid);
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[map(Uuid)]
+struct OrderId(Uuid);
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[map_owned(String)]
+#[from_ref(String)]
+#[ref_into(String)]
+struct Email {
+    #[ref_into(~.clone())]
+    #[from_ref(~.clone())]
+    value: String,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[from_owned(i32)]
+#[owned_into(i32)]
+struct Count(#[from(~.max(0))] i32);
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[map_owned(std::string::String)]
+struct Name(String);
+
+#[test]
+fn inner_to_newtype() {
+    let id: UserId = Uuid(123).into();
+    assert_eq!(UserId(Uuid(123)), id);
+
+    let id: UserId = (&Uuid(123)).into();
+    assert_eq!(UserId(Uuid(123)), id);
+
+    let email: Email = String::from("john@example.com").into();
+    assert_eq!(Email { value: "john@example.com".into() }, email);
+
+    let email: Email = (&String::from("john@example.com")).into();
+    assert_eq!(Email { value: "john@example.com".into() }, email);
+}
+
+#[test]
+fn newtype_to_inner() {
+    let uuid: Uuid = UserId(Uuid(123)).into();
+    assert_eq!(Uuid(123), uuid);
+
+    let uuid: Uuid = (&UserId(Uuid(123))).into();
+    assert_eq!(Uuid(123), uuid);
+
+    let email = Email { value: "john@example.com".into() };
+    let value: String = (&email).into();
+    assert_eq!("john@example.com", value);
+
+    let value: String = email.into();
+    assert_eq!("john@example.com", value);
+
+    let mut uuid = Uuid(0);
+    UserId(Uuid(123)).into_existing(&mut uuid);
+    assert_eq!(Uuid(123), uuid);
+}
+
+#[test]
+fn newtype_to_newtype() {
+    let order_id: OrderId = UserId(Uuid(123)).into();
+    assert_eq!(OrderId(Uuid(123)), order_id);
+
+    let user_id: UserId = OrderId(Uuid(123)).into();
+    assert_eq!(UserId(Uuid(123)), user_id);
+}
+
+#[test]
+fn member_actions() {
+    let count: Count = 5.into();
+    assert_eq!(Count(5), count);
+
+    let count: Count = (-5).into();
+    assert_eq!(Count(0), count);
+
+    let value: i32 = Count(5).into();
+    assert_eq!(5, value);
+}
+
+#[test]
+fn qualified_inner_type() {
+    let name: Name = String::from("John").into();
+    assert_eq!(Name("John".into()), name);
+
+    let name: String = name.into();
+    assert_eq!("John", name);
+}
@@ -0,0 +1,46 @@
+use o2o::o2o;
+
+type EmailError = String;
+
+fn validate(email: String) -> Result<String, EmailError> {
+    if email.contains('@') { Ok(email) } else { Err(format!("'{}' is not an email", email)) }
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[try_map_owned(String, EmailError)]
+#[try_from_ref(String, EmailError)]
+struct Email(
+    #[from_owned(validate(~)?)]
+    #[from_ref(validate(~.clone())?)]
+    String
+);
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(transparent)]
+#[try_map_owned(i32, String)]
+struct Count(i32);
+
+#[test]
+fn try_from() {
+    let email: Email = String::from("john@example.com").try_into().unwrap();
+    assert_eq!(Email("john@example.com".into()), email);
+
+    let email = Email::try_from(&String::from("john@example.com")).unwrap();
+    assert_eq!(Email("john@example.com".into()), email);
+
+    let email: Result<Email, String> = String::from("john").try_into();
+    assert_eq!(Err("'john' is not an email".to_string()), email);
+
+    let count: Count = 5.try_into().unwrap();
+    assert_eq!(Count(5), count);
+}
+
+#[test]
+fn try_into() {
+    let value: String = Email("john@example.com".into()).try_into().unwrap();
+    assert_eq!("john@example.com", value);
+
+    let value: i32 = Count(5).try_into().unwrap();
+    assert_eq!(5, value);
+}
@@ -0,0 +1,111 @@
+use o2o::o2o;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entity {
+    id: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, o2o)]
+#[map(Entity)]
+struct EntityDto {
+    id: i32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Page<T> {
+    items: Vec<T>,
+    first: Option<T>,
+    total: usize,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[from_owned(Page<T>| infer_bounds(T))]
+#[owned_into(Page<T>| infer_bounds(T))]
+#[from_ref(Page<T>| infer_bounds(T))]
+struct PageDto<U> {
+    #[from_owned(~.into_iter().map(Into::into).collect())]
+    #[owned_into(~.into_iter().map(Into::into).collect())]
+    #[from_ref(~.iter().map(Into::into).collect())]
+    items: Vec<U>,
+    #[o2o(map_opt)]
+    first: Option<U>,
+    total: usize,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[from_owned(Page<T>| infer_bounds(T))]
+#[where_clause(T: Into<U>, U: Default)]
+struct FirstItemDto<U> {
+    #[from(~.map(Into::into).unwrap_or_default())]
+    first: U,
+    total: usize,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[from_owned(Page<i32>| infer_bounds)]
+struct NumberPageDto<U> {
+    #[from(~.into_iter().map(Into::into).collect())]
+    items: Vec<U>,
+    total: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Response<T> {
+    Ok(T),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[from_owned(Response<T>| infer_bounds(T), convert_fields)]
+#[owned_into(Response<T>| infer_bounds(T), convert_fields)]
+enum ResponseDto<U> {
+    Ok(U),
+    Empty,
+}
+
+fn page() -> Page<Entity> {
+    Page { items: vec![Entity { id: 1 }, Entity { id: 2 }], first: Some(Entity { id: 1 }), total: 2 }
+}
+
+fn page_dto() -> PageDto<EntityDto> {
+    PageDto { items: vec![EntityDto { id: 1 }, EntityDto { id: 2 }], first: Some(EntityDto { id: 1 }), total: 2 }
+}
+
+#[test]
+fn from() {
+    let dto: PageDto<EntityDto> = page().into();
+    assert_eq!(page_dto(), dto);
+
+    let dto: PageDto<EntityDto> = (&page()).into();
+    assert_eq!(page_dto(), dto);
+}
+
+#[test]
+fn into() {
+    let page_: Page<Entity> = page_dto().into();
+    assert_eq!(page(), page_);
+}
+
+#[test]
+fn where_clause_override() {
+    let dto: FirstItemDto<EntityDto> = page().into();
+    assert_eq!(FirstItemDto { first: EntityDto { id: 1 }, total: 2 }, dto);
+
+    let dto: FirstItemDto<i64> = Page { items: vec![], first: None::<i32>, total: 0 }.into();
+    assert_eq!(FirstItemDto { first: 0, total: 0 }, dto);
+}
+
+#[test]
+fn concrete_type_argument() {
+    let dto: NumberPageDto<i64> = Page { items: vec![1, 2], first: None, total: 2 }.into();
+    assert_eq!(NumberPageDto { items: vec![1i64, 2], total: 2 }, dto);
+}
+
+#[test]
+fn enums() {
+    let dto: ResponseDto<EntityDto> = Response::Ok(Entity { id: 1 }).into();
+    assert_eq!(ResponseDto::Ok(EntityDto { id: 1 }), dto);
+
+    let response: Response<Entity> = ResponseDto::<EntityDto>::Empty.into();
+    assert_eq!(Response::Empty, response);
+}
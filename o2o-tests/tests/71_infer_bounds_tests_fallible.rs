@@ -0,0 +1,48 @@
+use o2o::o2o;
+
+#[derive(Debug, PartialEq)]
+struct Entity {
+    id: i32,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[try_map_owned(Entity, String)]
+struct EntityDto {
+    #[from(~.try_into().map_err(|_| format!("id {} is out of range", ~))?)]
+    #[into(~.into())]
+    id: u8,
+}
+
+#[derive(Debug, PartialEq)]
+struct Page<T> {
+    items: Vec<T>,
+    first: Option<T>,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[try_from_owned(Page<T>, String| infer_bounds(T))]
+#[owned_try_into(Page<T>, String| infer_bounds(T))]
+struct PageDto<U> {
+    #[map((~.into_iter().map(TryInto::try_into).collect::<Result<Vec<_>, _>>())?)]
+    items: Vec<U>,
+    #[o2o(map_opt)]
+    first: Option<U>,
+}
+
+#[test]
+fn try_from() {
+    let page = Page { items: vec![Entity { id: 1 }, Entity { id: 2 }], first: Some(Entity { id: 1 }) };
+    let dto: PageDto<EntityDto> = page.try_into().unwrap();
+    assert_eq!(PageDto { items: vec![EntityDto { id: 1 }, EntityDto { id: 2 }], first: Some(EntityDto { id: 1 }) }, dto);
+
+    let page = Page { items: vec![Entity { id: 1 }, Entity { id: 1000 }], first: None };
+    let dto: Result<PageDto<EntityDto>, String> = page.try_into();
+    assert_eq!(Err("id 1000 is out of range".to_string()), dto);
+}
+
+#[test]
+fn try_into() {
+    let dto = PageDto { items: vec![EntityDto { id: 1 }], first: None };
+    let page: Page<Entity> = dto.try_into().unwrap();
+    assert_eq!(Page { items: vec![Entity { id: 1 }], first: None }, page);
+}
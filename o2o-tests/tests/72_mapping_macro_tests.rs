@@ -0,0 +1,81 @@
+mod generated {
+    #[derive(Debug, PartialEq)]
+    pub struct Shape {
+        pub kind: i32,
+        pub width: f32,
+        pub height: f32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Rect {
+        pub w: f32,
+        pub h: f32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Id(pub i32);
+}
+
+mod mappings {
+    use super::generated::{Color, Id, Rect, Shape};
+
+    fn positive(value: i32) -> Result<i32, String> {
+        if value > 0 { Ok(value) } else { Err("Id must be positive".to_string()) }
+    }
+
+    o2o::mapping! {
+        #[from_owned(Shape)]
+        #[ref_into(Shape)]
+        #[ghosts(kind: { 1 })]
+        struct Rect {
+            #[map(width)]
+            w: f32,
+            #[map(height)]
+            h: f32,
+        }
+
+        #[map_owned(i32| _ => panic!("Unknown color"))]
+        enum Color {
+            #[literal(1)] Red,
+            #[literal(2)] Green,
+        }
+
+        #[try_from_owned(i32, String)]
+        struct Id(#[from(positive(@)?)] i32);
+    }
+}
+
+use generated::{Color, Id, Rect, Shape};
+
+#[test]
+fn struct_mapping() {
+    let rect: Rect = Shape { kind: 1, width: 2.0, height: 3.0 }.into();
+    assert_eq!(Rect { w: 2.0, h: 3.0 }, rect);
+
+    let shape: Shape = (&rect).into();
+    assert_eq!(Shape { kind: 1, width: 2.0, height: 3.0 }, shape);
+}
+
+#[test]
+fn enum_mapping() {
+    let color: Color = 2.into();
+    assert_eq!(Color::Green, color);
+
+    let value: i32 = Color::Red.into();
+    assert_eq!(1, value);
+}
+
+#[test]
+fn fallible_mapping() {
+    let id: Id = 5.try_into().unwrap();
+    assert_eq!(Id(5), id);
+
+    let id: Result<Id, String> = (-5).try_into();
+    assert_eq!(Err("Id must be positive".to_string()), id);
+}
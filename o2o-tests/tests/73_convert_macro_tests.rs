@@ -0,0 +1,110 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use o2o::convert;
+
+#[derive(Clone)]
+struct Person {
+    id: i32,
+    name: String,
+    age: u8,
+    address: Address,
+}
+
+#[derive(Clone)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct PersonDto {
+    id: i32,
+    name: String,
+    city: String,
+    next_age: u8,
+    note: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapper(i32, String);
+
+fn person() -> Person {
+    Person { id: 123, name: "John".into(), age: 42, address: Address { city: "Paris".into() } }
+}
+
+fn parse_id(value: &str) -> Result<i32, String> {
+    value.parse().map_err(|_| format!("'{}' is not a valid id", value))
+}
+
+#[test]
+fn owned() {
+    let person = person();
+
+    let dto = convert!(person => PersonDto { id, name: ~.to_uppercase(), city: @.address.city, next_age: @.age + 1, note: None });
+
+    assert_eq!(PersonDto { id: 123, name: "JOHN".into(), city: "Paris".into(), next_age: 43, note: None }, dto);
+}
+
+#[test]
+fn owned_update() {
+    let person = person();
+
+    let dto = convert!(person => PersonDto { id, name, ..Default::default() });
+
+    assert_eq!(PersonDto { id: 123, name: "John".into(), ..Default::default() }, dto);
+}
+
+#[test]
+fn borrowed() {
+    let person = person();
+
+    let dto = convert!(&person => PersonDto { id, name: ~.clone(), city: @.address.city.clone(), next_age: @.age + 1, note: None });
+
+    assert_eq!(PersonDto { id: 123, name: "John".into(), city: "Paris".into(), next_age: 43, note: None }, dto);
+    assert_eq!("John", person.name);
+}
+
+#[test]
+fn unnamed() {
+    let person = person();
+
+    let wrapper = convert!(&person => Wrapper { 0: @.id * 2, 1: @.address.city.clone() });
+
+    assert_eq!(Wrapper(246, "Paris".into()), wrapper);
+}
+
+fn parse_person(values: (&str, &str)) -> Result<PersonDto, String> {
+    convert!(values =>? PersonDto { id: parse_id(@.0)?, name: @.1.into(), ..Default::default() })
+}
+
+async fn find_city(id: i32) -> Result<String, String> {
+    if id == 123 { Ok("Paris".into()) } else { Err(format!("city of {} not found", id)) }
+}
+
+async fn load_person(values: (&str, &str)) -> Result<PersonDto, String> {
+    let id = parse_id(values.0)?;
+    convert!(values =>? PersonDto { id: id, name: @.1.into(), city: find_city(id).await?, ..Default::default() })
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn fallible() {
+    assert_eq!(Ok(PersonDto { id: 123, name: "John".into(), ..Default::default() }), parse_person(("123", "John")));
+    assert_eq!(Err("'abc' is not a valid id".into()), parse_person(("abc", "John")));
+}
+
+#[test]
+fn fallible_async() {
+    assert_eq!(Ok(PersonDto { id: 123, name: "John".into(), city: "Paris".into(), ..Default::default() }), block_on(load_person(("123", "John"))));
+    assert_eq!(Err("city of 7 not found".into()), block_on(load_person(("7", "John"))));
+}
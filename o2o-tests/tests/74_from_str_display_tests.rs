@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use o2o::o2o;
+use o2o::traits::ParseEnumError;
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(from_str)]
+#[o2o(display)]
+enum Color {
+    #[literal("red")]
+    Red,
+    #[literal("green")]
+    Green,
+    Blue,
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(from_str(case_insensitive, rename_all("snake_case")))]
+#[o2o(display(rename_all("snake_case")))]
+enum HttpMethod {
+    Get,
+    Post,
+    #[literal("DELETE")]
+    Delete,
+    PostForm,
+}
+
+#[derive(Debug, PartialEq)]
+enum MyError {
+    UnknownShape,
+}
+
+impl From<ParseEnumError> for MyError {
+    fn from(_: ParseEnumError) -> Self {
+        MyError::UnknownShape
+    }
+}
+
+#[derive(Debug, PartialEq, o2o)]
+#[o2o(from_str(MyError| rename_all("SCREAMING_SNAKE_CASE")))]
+#[o2o(display(rename_all("SCREAMING_SNAKE_CASE")))]
+#[map_owned(i32| _ => panic!("Not supported"))]
+enum Shape {
+    #[literal(i32| 4)]
+    Square,
+    #[literal(i32| 3)]
+    Triangle,
+}
+
+#[test]
+fn from_str() {
+    assert_eq!(Ok(Color::Red), Color::from_str("red"));
+    assert_eq!(Ok(Color::Green), "green".parse());
+    assert_eq!(Ok(Color::Blue), "Blue".parse());
+    assert_eq!(Err(ParseEnumError), "blue".parse::<Color>());
+    assert_eq!(Err(ParseEnumError), "Red".parse::<Color>());
+}
+
+#[test]
+fn display() {
+    assert_eq!("red", Color::Red.to_string());
+    assert_eq!("green", Color::Green.to_string());
+    assert_eq!("Blue", Color::Blue.to_string());
+}
+
+#[test]
+fn round_trip() {
+    for color in [Color::Red, Color::Green, Color::Blue] {
+        assert_eq!(Ok(&color), color.to_string().parse().as_ref());
+    }
+    for method in [HttpMethod::Get, HttpMethod::Post, HttpMethod::Delete, HttpMethod::PostForm] {
+        assert_eq!(Ok(&method), method.to_string().parse().as_ref());
+    }
+}
+
+#[test]
+fn case_insensitive_rename_all() {
+    assert_eq!(Ok(HttpMethod::Get), "get".parse());
+    assert_eq!(Ok(HttpMethod::Get), "GET".parse());
+    assert_eq!(Ok(HttpMethod::Delete), "delete".parse());
+    assert_eq!(Ok(HttpMethod::PostForm), "Post_Form".parse());
+    assert_eq!(Err(ParseEnumError), "PostForm".parse::<HttpMethod>());
+
+    assert_eq!("post_form", HttpMethod::PostForm.to_string());
+    assert_eq!("DELETE", HttpMethod::Delete.to_string());
+}
+
+#[test]
+fn custom_error() {
+    assert_eq!(Ok(Shape::Triangle), "TRIANGLE".parse());
+    assert_eq!(Err(MyError::UnknownShape), "Circle".parse::<Shape>());
+    assert_eq!("SQUARE", Shape::Square.to_string());
+
+    let shape: Shape = 4.into();
+    assert_eq!(Shape::Square, shape);
+}
+
+#[test]
+fn error_display() {
+    assert_eq!("matching variant not found", ParseEnumError.to_string());
+}
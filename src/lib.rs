@@ -1,6 +1,12 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(any(feature = "syn1", feature = "syn2"))]
 pub use o2o_macros::*;
 
@@ -6,3 +6,445 @@ pub trait TryIntoExisting<T> {
     type Error;
     fn try_into_existing(self, other: &mut T) -> Result<(), Self::Error>;
 }
+
+/// Updates `other` only if there is `Some` value.
+impl<T: IntoExisting<U>, U> IntoExisting<U> for Option<T> {
+    fn into_existing(self, other: &mut U) {
+        if let Some(value) = self {
+            value.into_existing(other)
+        }
+    }
+}
+
+/// Updates `other` only if there is `Some` value.
+impl<'a, T, U> IntoExisting<U> for &'a Option<T> where &'a T: IntoExisting<U> {
+    fn into_existing(self, other: &mut U) {
+        if let Some(value) = self {
+            value.into_existing(other)
+        }
+    }
+}
+
+/// Updates `other` only if there is `Some` value.
+impl<T: TryIntoExisting<U>, U> TryIntoExisting<U> for Option<T> {
+    type Error = T::Error;
+    fn try_into_existing(self, other: &mut U) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.try_into_existing(other),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Updates `other` only if there is `Some` value.
+impl<'a, T, U> TryIntoExisting<U> for &'a Option<T> where &'a T: TryIntoExisting<U> {
+    type Error = <&'a T as TryIntoExisting<U>>::Error;
+    fn try_into_existing(self, other: &mut U) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.try_into_existing(other),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Updates elements of `other` by index. `other` is resized to the length of `self`,
+/// new elements are created with `Default::default()` before being updated.
+#[cfg(feature = "alloc")]
+impl<T: IntoExisting<U>, U: Default> IntoExisting<alloc::vec::Vec<U>> for alloc::vec::Vec<T> {
+    fn into_existing(self, other: &mut alloc::vec::Vec<U>) {
+        other.resize_with(self.len(), Default::default);
+        for (value, other) in self.into_iter().zip(other.iter_mut()) {
+            value.into_existing(other)
+        }
+    }
+}
+
+/// Updates elements of `other` by index. `other` is resized to the length of `self`,
+/// new elements are created with `Default::default()` before being updated.
+#[cfg(feature = "alloc")]
+impl<'a, T, U: Default> IntoExisting<alloc::vec::Vec<U>> for &'a alloc::vec::Vec<T> where &'a T: IntoExisting<U> {
+    fn into_existing(self, other: &mut alloc::vec::Vec<U>) {
+        other.resize_with(self.len(), Default::default);
+        for (value, other) in self.iter().zip(other.iter_mut()) {
+            value.into_existing(other)
+        }
+    }
+}
+
+/// Updates elements of `other` by index, stopping at the first error. `other` is resized to the length of `self`,
+/// new elements are created with `Default::default()` before being updated.
+#[cfg(feature = "alloc")]
+impl<T: TryIntoExisting<U>, U: Default> TryIntoExisting<alloc::vec::Vec<U>> for alloc::vec::Vec<T> {
+    type Error = T::Error;
+    fn try_into_existing(self, other: &mut alloc::vec::Vec<U>) -> Result<(), Self::Error> {
+        other.resize_with(self.len(), Default::default);
+        for (value, other) in self.into_iter().zip(other.iter_mut()) {
+            value.try_into_existing(other)?
+        }
+        Ok(())
+    }
+}
+
+/// Updates elements of `other` by index, stopping at the first error. `other` is resized to the length of `self`,
+/// new elements are created with `Default::default()` before being updated.
+#[cfg(feature = "alloc")]
+impl<'a, T, U: Default> TryIntoExisting<alloc::vec::Vec<U>> for &'a alloc::vec::Vec<T> where &'a T: TryIntoExisting<U> {
+    type Error = <&'a T as TryIntoExisting<U>>::Error;
+    fn try_into_existing(self, other: &mut alloc::vec::Vec<U>) -> Result<(), Self::Error> {
+        other.resize_with(self.len(), Default::default);
+        for (value, other) in self.iter().zip(other.iter_mut()) {
+            value.try_into_existing(other)?
+        }
+        Ok(())
+    }
+}
+
+/// Upserts entries of `other` by key: existing values are updated,
+/// missing ones are created with `Default::default()` before being updated.
+/// Entries of `other` that are absent from `self` are left untouched.
+#[cfg(feature = "alloc")]
+macro_rules! impl_into_existing_for_map {
+    ($map:ident, $($key_bound:tt)+) => {
+        impl<K: $($key_bound)+, T: IntoExisting<U>, U: Default> IntoExisting<$map<K, U>> for $map<K, T> {
+            fn into_existing(self, other: &mut $map<K, U>) {
+                for (key, value) in self {
+                    value.into_existing(other.entry(key).or_default())
+                }
+            }
+        }
+
+        impl<'a, K: Clone + $($key_bound)+, T, U: Default> IntoExisting<$map<K, U>> for &'a $map<K, T> where &'a T: IntoExisting<U> {
+            fn into_existing(self, other: &mut $map<K, U>) {
+                for (key, value) in self {
+                    value.into_existing(other.entry(key.clone()).or_default())
+                }
+            }
+        }
+
+        impl<K: $($key_bound)+, T: TryIntoExisting<U>, U: Default> TryIntoExisting<$map<K, U>> for $map<K, T> {
+            type Error = T::Error;
+            fn try_into_existing(self, other: &mut $map<K, U>) -> Result<(), Self::Error> {
+                for (key, value) in self {
+                    value.try_into_existing(other.entry(key).or_default())?
+                }
+                Ok(())
+            }
+        }
+
+        impl<'a, K: Clone + $($key_bound)+, T, U: Default> TryIntoExisting<$map<K, U>> for &'a $map<K, T> where &'a T: TryIntoExisting<U> {
+            type Error = <&'a T as TryIntoExisting<U>>::Error;
+            fn try_into_existing(self, other: &mut $map<K, U>) -> Result<(), Self::Error> {
+                for (key, value) in self {
+                    value.try_into_existing(other.entry(key.clone()).or_default())?
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+impl_into_existing_for_map!(BTreeMap, Ord);
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+impl_into_existing_for_map!(HashMap, Eq + core::hash::Hash);
+
+/// State of a builder field, that has not been supplied yet (see ghost fields without default value).
+pub struct Unset;
+
+/// State of a builder field, that has been supplied.
+pub struct Set<T>(pub T);
+
+/// Conversion that needs an additional context argument, e.g. a locale or a clock.
+pub trait FromWith<T, C> {
+    fn from_with(value: T, ctx: C) -> Self;
+}
+
+/// Fallible conversion that needs an additional context argument, e.g. a locale or a clock.
+pub trait TryFromWith<T, C>: Sized {
+    type Error;
+    fn try_from_with(value: T, ctx: C) -> Result<Self, Self::Error>;
+}
+
+/// Conversion that needs an additional context argument, e.g. a locale or a clock.
+pub trait IntoWith<T, C> {
+    fn into_with(self, ctx: C) -> T;
+}
+
+/// Fallible conversion that needs an additional context argument, e.g. a locale or a clock.
+pub trait TryIntoWith<T, C> {
+    type Error;
+    fn try_into_with(self, ctx: C) -> Result<T, Self::Error>;
+}
+
+/// Fallible conversion that needs to `.await`, e.g. to resolve foreign keys through a repository.
+///
+/// The returned future is not required to be `Send`, so it can't be spawned on multi-threaded executors directly.
+#[cfg(feature = "async")]
+pub trait AsyncTryFrom<T>: Sized {
+    type Error;
+    fn try_from_async(value: T) -> impl core::future::Future<Output = Result<Self, Self::Error>>;
+}
+
+/// Fallible conversion that needs to `.await`, e.g. to resolve foreign keys through a repository.
+///
+/// The returned future is not required to be `Send`, so it can't be spawned on multi-threaded executors directly.
+#[cfg(feature = "async")]
+pub trait AsyncTryInto<T> {
+    type Error;
+    fn try_into_async(self) -> impl core::future::Future<Output = Result<T, Self::Error>>;
+}
+
+/// Accumulates errors of individual fields when `collect_errors` trait instruction parameter is used.
+pub trait ErrorCollector<E>: Default {
+    fn push(&mut self, field: &'static str, error: E);
+    fn is_empty(&self) -> bool;
+}
+
+/// Error of a single field, along with the path to this field. Used by `error_paths` trait instruction parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError<E> {
+    pub path: FieldPath,
+    pub error: E,
+}
+
+impl<E> FieldError<E> {
+    pub fn new(path: &'static str, error: E) -> Self {
+        FieldError { path: FieldPath::new(path), error }
+    }
+
+    /// Prepends `path` to the path of this error, e.g. when it comes from a nested conversion.
+    pub fn prefixed(self, path: &'static str) -> Self {
+        FieldError { path: self.path.prefixed(path), error: self.error }
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for FieldError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+/// Turns an error of a nested conversion into [FieldError]. Errors that carry a path already
+/// get `path` prepended to it, so paths compose across types that use `error_paths`.
+pub trait IntoFieldError<E> {
+    fn into_field_error(self, path: &'static str) -> FieldError<E>;
+}
+
+impl<E> IntoFieldError<E> for E {
+    fn into_field_error(self, path: &'static str) -> FieldError<E> {
+        FieldError::new(path, self)
+    }
+}
+
+impl<E> IntoFieldError<E> for FieldError<E> {
+    fn into_field_error(self, path: &'static str) -> FieldError<E> {
+        self.prefixed(path)
+    }
+}
+
+const MAX_PATH_DEPTH: usize = 8;
+
+/// Dot separated path to a field, e.g. `address.zip_code`.
+///
+/// Paths are stored without allocation as a list of segments. When more than 8 segments get
+/// prepended, the outermost ones are dropped.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldPath {
+    segments: [&'static str; MAX_PATH_DEPTH],
+    start: usize,
+}
+
+impl FieldPath {
+    pub fn new(path: &'static str) -> Self {
+        let mut segments = [""; MAX_PATH_DEPTH];
+        segments[MAX_PATH_DEPTH - 1] = path;
+        FieldPath { segments, start: MAX_PATH_DEPTH - 1 }
+    }
+
+    pub fn prefixed(mut self, path: &'static str) -> Self {
+        if self.start > 0 {
+            self.start -= 1;
+            self.segments[self.start] = path;
+        }
+        self
+    }
+
+    pub fn segments(&self) -> &[&'static str] {
+        &self.segments[self.start..]
+    }
+
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.segments().iter().enumerate().flat_map(|(i, x)| (i > 0).then_some('.').into_iter().chain(x.chars()))
+    }
+}
+
+impl core::fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, segment) in self.segments().iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(segment)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for FieldPath {
+    fn eq(&self, other: &FieldPath) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for FieldPath {}
+
+impl PartialEq<str> for FieldPath {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for FieldPath {
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<FieldPath> for &str {
+    fn eq(&self, other: &FieldPath) -> bool {
+        other == self
+    }
+}
+
+/// Errors of all fields that failed to convert, in the order of their declaration.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionErrors<E> {
+    pub errors: alloc::vec::Vec<(&'static str, E)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Default for ConversionErrors<E> {
+    fn default() -> Self {
+        ConversionErrors { errors: alloc::vec::Vec::new() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ErrorCollector<E> for ConversionErrors<E> {
+    fn push(&mut self, field: &'static str, error: E) {
+        self.errors.push((field, error));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: core::fmt::Display> core::fmt::Display for ConversionErrors<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, (field, error)) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}: {}", field, error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Field of the target, whose value differs from the mapped value of the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldChange {
+    pub path: &'static str,
+}
+
+impl FieldChange {
+    pub fn new(path: &'static str) -> Self {
+        FieldChange { path }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub type FieldChanges = alloc::vec::Vec<FieldChange>;
+
+/// Lists fields that `into_existing` would change, without changing them. Generated by `diff` trait instruction parameter.
+#[cfg(feature = "alloc")]
+pub trait DiffInto<T> {
+    fn diff_into(self, other: &T) -> FieldChanges;
+}
+
+/// Lists changes only if there is `Some` value, the same way `into_existing` updates `other` only if there is `Some` value.
+#[cfg(feature = "alloc")]
+impl<T: DiffInto<U>, U> DiffInto<U> for Option<T> {
+    fn diff_into(self, other: &U) -> FieldChanges {
+        self.map_or_else(FieldChanges::new, |value| value.diff_into(other))
+    }
+}
+
+/// Lists changes only if there is `Some` value, the same way `into_existing` updates `other` only if there is `Some` value.
+#[cfg(feature = "alloc")]
+impl<'a, T, U> DiffInto<U> for &'a Option<T> where &'a T: DiffInto<U> {
+    fn diff_into(self, other: &U) -> FieldChanges {
+        self.as_ref().map_or_else(FieldChanges::new, |value| value.diff_into(other))
+    }
+}
+
+/// Field manifest of a struct. Can be derived with `#[derive(o2o::Shape)]`.
+pub trait Shape {
+    const FIELDS: &'static [&'static str];
+}
+
+#[doc(hidden)]
+pub const fn shape_has_field(fields: &[&str], name: &str) -> bool {
+    let mut i = 0;
+    while i < fields.len() {
+        let field = fields[i].as_bytes();
+        let name = name.as_bytes();
+        if field.len() == name.len() {
+            let mut j = 0;
+            while j < field.len() && field[j] == name[j] {
+                j += 1;
+            }
+            if j == field.len() {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Error of `FromStr` implementations generated by `from_str` instruction, when the string doesn't match any variant.
+/// User-supplied error types are created with `From<ParseEnumError>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEnumError;
+
+impl core::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("matching variant not found")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEnumError {}
+
+/// Error of fallible conversions generated with `tag(...)` instruction parameter, when a field of the matched variant is `None`.
+/// User-supplied error types are created with `From<MissingFieldError>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingFieldError(pub &'static str);
+
+impl core::fmt::Display for MissingFieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "field '{}' is missing", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingFieldError {}